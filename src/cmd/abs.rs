@@ -1,4 +1,4 @@
-use crate::{direction::TurnDirection, map::Map};
+use crate::direction::TurnDirection;
 
 /// Navigation command where distances are specified in absolute game cell
 /// counts.
@@ -1,27 +1,60 @@
-use crate::TurnDirection;
+use crate::{CardinalDirection, TurnDirection};
 
 use crate::lang::{
     syntax::{
-        DistanceNounPhrase, LeftRightTurnPrepPhrase, StreetNounPhrase, TurnDirectionNoun,
-        TurnDirectionNounPhrase, TurnableNounPhrase,
+        CardinalDirectionNoun, CardinalPrepPhrase, DistanceNounPhrase, LeftRightTurnPrepPhrase,
+        Position, StreetNounPhrase, TurnDirectionNoun, TurnDirectionNounPhrase, TurnableNounPhrase,
     },
-    Sentence,
+    Directions, Sentence,
 };
 
 /// Navigation command where distances are in terms of landmarks on that map
 /// that are close to the player.s
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CtxCommand {
     Forward(CtxCommandDistance),
     Rotate(TurnDirection),
+
+    /// Rotate to face the given cardinal direction.
+    Face(CardinalDirection),
 }
 
 /// Navigation command to move forward.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
 pub enum CtxCommandDistance {
     ThisOrNextStreet(Option<TurnDirection>),
     NthStreet(usize, Option<TurnDirection>),
+
+    /// Move forward across the `n`th intersecting street, stopping just past
+    /// it.
+    PastNthStreet(usize),
+
+    /// Move forward to the last street before the current street ends. If a
+    /// `TurnDirection` is given, it is the last street on that side.
+    LastStreet(Option<TurnDirection>),
+
+    /// Move forward to the street with the given name. If a `TurnDirection`
+    /// is given, the street must intersect on that side of the player.
+    NamedStreet(String, Option<TurnDirection>),
+
+    /// Move forward until the player is beside the building with the given
+    /// name. If a `TurnDirection` is given, the building must be on that side
+    /// of the player.
+    Landmark(String, Option<TurnDirection>),
+
+    /// Move forward past the building with the given name and then to the
+    /// next street in the given `TurnDirection`.
+    PastLandmark(String, TurnDirection),
+
+    /// Move forward until the end of the current street.
+    EndOfStreet,
+}
+
+impl From<Directions> for Vec<CtxCommand> {
+    fn from(d: Directions) -> Self {
+        d.0.into_iter().flat_map(Vec::<CtxCommand>::from).collect()
+    }
 }
 
 impl From<Sentence> for Vec<CtxCommand> {
@@ -31,6 +64,14 @@ impl From<Sentence> for Vec<CtxCommand> {
                 let rot = pp.into();
                 vec![CtxCommand::Rotate(rot)]
             }
+            Sentence::EstáTurnPpEnfrenteNp(pp, np) => {
+                // The destination is across the street from the landmark, so
+                // the landmark is on the opposite side.
+                let rot = pp.into();
+                let other_side = pp.0 .0.opposite().into();
+                let dist = CtxCommandDistance::Landmark(np.to_string(), Some(other_side));
+                vec![CtxCommand::Forward(dist), CtxCommand::Rotate(rot)]
+            }
             Sentence::EstáEnNpPp(np, pp) => {
                 let rot = pp.into();
                 let dist = CtxCommandDistance::from_turnable_np(np, rot);
@@ -41,6 +82,21 @@ impl From<Sentence> for Vec<CtxCommand> {
                 let dist = CtxCommandDistance::ThisOrNextStreet(Some(rot));
                 vec![CtxCommand::Forward(dist), CtxCommand::Rotate(rot)]
             }
+            Sentence::GiraCardinalPp(pp) | Sentence::VeCardinalPp(pp) => {
+                vec![CtxCommand::Face(pp.into())]
+            }
+            Sentence::GiraPpDespuésNp(pp, np) => {
+                let rot = pp.into();
+                let dist = CtxCommandDistance::PastLandmark(np.to_string(), rot);
+                vec![CtxCommand::Forward(dist), CtxCommand::Rotate(rot)]
+            }
+            Sentence::GiraPpAlFinalNp(pp, _) => {
+                let rot = pp.into();
+                vec![
+                    CtxCommand::Forward(CtxCommandDistance::EndOfStreet),
+                    CtxCommand::Rotate(rot),
+                ]
+            }
             Sentence::GiraNpPp(np, pp) => {
                 let rot = pp.into();
                 let dist = CtxCommandDistance::from_turnable_np(np, rot);
@@ -51,14 +107,35 @@ impl From<Sentence> for Vec<CtxCommand> {
                 let dist = CtxCommandDistance::from_turnable_np(np, rot);
                 vec![CtxCommand::Forward(dist), CtxCommand::Rotate(rot)]
             }
-            Sentence::ContinúaNpNp(_, pp) => {
-                let dist = pp.into();
+            Sentence::DaNp(_) | Sentence::RegresaPorDondeViniste => {
+                vec![CtxCommand::Rotate(TurnDirection::Around)]
+            }
+            Sentence::ContinúaNpNp(_, np)
+            | Sentence::CaminaNpNp(_, np)
+            | Sentence::VeNpNp(_, np) => {
+                let dist = np.into();
                 vec![CtxCommand::Forward(dist)]
             }
-            Sentence::ContinúaNpHastaNp(_, pp) => {
-                let dist = pp.into();
+            Sentence::CruzaNp(np) | Sentence::PasaNp(np) => {
+                let dist = match np {
+                    DistanceNounPhrase::NQuadras(n) => CtxCommandDistance::PastNthStreet(n.get()),
+                };
                 vec![CtxCommand::Forward(dist)]
             }
+            Sentence::ContinúaNpHastaNp(_, np, pp) => {
+                // The turn direction only identifies the street. The player
+                // does not turn onto it.
+                let dir = pp.map(TurnDirection::from);
+                let dist = CtxCommandDistance::from_street_np(np, dir);
+                vec![CtxCommand::Forward(dist)]
+            }
+            Sentence::ContinúaNpHastaLandmarkNp(_, np) => {
+                let dist = CtxCommandDistance::Landmark(np.to_string(), None);
+                vec![CtxCommand::Forward(dist)]
+            }
+            Sentence::ContinúaNpHastaFinalNp(_, _) => {
+                vec![CtxCommand::Forward(CtxCommandDistance::EndOfStreet)]
+            }
         }
     }
 }
@@ -86,19 +163,28 @@ impl From<TurnDirectionNoun> for TurnDirection {
     }
 }
 
-impl From<DistanceNounPhrase> for CtxCommandDistance {
-    fn from(np: DistanceNounPhrase) -> Self {
-        match np {
-            DistanceNounPhrase::NQuadras(n) => Self::NthStreet(n, None),
+impl From<CardinalPrepPhrase> for CardinalDirection {
+    fn from(pp: CardinalPrepPhrase) -> Self {
+        let n = pp.0;
+        n.into()
+    }
+}
+
+impl From<CardinalDirectionNoun> for CardinalDirection {
+    fn from(n: CardinalDirectionNoun) -> Self {
+        match n {
+            CardinalDirectionNoun::Norte => Self::North,
+            CardinalDirectionNoun::Sur => Self::South,
+            CardinalDirectionNoun::Este => Self::East,
+            CardinalDirectionNoun::Oeste => Self::West,
         }
     }
 }
 
-impl From<StreetNounPhrase> for CtxCommandDistance {
-    fn from(np: StreetNounPhrase) -> Self {
+impl From<DistanceNounPhrase> for CtxCommandDistance {
+    fn from(np: DistanceNounPhrase) -> Self {
         match np {
-            StreetNounPhrase::LaCalle => Self::ThisOrNextStreet(None),
-            StreetNounPhrase::LaCalleOrd(ord) => Self::NthStreet(ord.value(), None),
+            DistanceNounPhrase::NQuadras(n) => Self::NthStreet(n.get(), None),
         }
     }
 }
@@ -106,12 +192,18 @@ impl From<StreetNounPhrase> for CtxCommandDistance {
 impl CtxCommandDistance {
     fn from_turnable_np(t_np: TurnableNounPhrase, dir: TurnDirection) -> Self {
         match t_np {
-            TurnableNounPhrase::Street(StreetNounPhrase::LaCalle) => {
-                CtxCommandDistance::ThisOrNextStreet(Some(dir))
-            }
-            TurnableNounPhrase::Street(StreetNounPhrase::LaCalleOrd(ord)) => {
-                CtxCommandDistance::NthStreet(ord.value(), Some(dir))
-            }
+            TurnableNounPhrase::Street(s_np) => Self::from_street_np(s_np, Some(dir)),
+        }
+    }
+
+    fn from_street_np(s_np: StreetNounPhrase, dir: Option<TurnDirection>) -> Self {
+        match s_np {
+            StreetNounPhrase::LaCalle => CtxCommandDistance::ThisOrNextStreet(dir),
+            StreetNounPhrase::LaCalleOrd(ord) => match ord.position() {
+                Position::Nth(n) => CtxCommandDistance::NthStreet(n, dir),
+                Position::Last => CtxCommandDistance::LastStreet(dir),
+            },
+            StreetNounPhrase::Named(name) => CtxCommandDistance::NamedStreet(name.to_string(), dir),
         }
     }
 }
@@ -4,20 +4,27 @@ mod ctx;
 pub use abs::AbsoluteCommand;
 pub use ctx::{CtxCommand, CtxCommandDistance};
 
-use crate::{direction::TurnDirection, Map, Pose};
+use crate::{
+    direction::TurnDirection,
+    map::{Edge, RoadGraph},
+    Map, Pose,
+};
+use iter_tools::Itertools;
 
-/// Uses the context of the `Map` and the player's `Pose` to transform a series
-/// of `CtxCommand`s into `AbsCommand`s.
+/// Uses the context of the `Map`, its `RoadGraph` and the player's `Pose` to
+/// transform a series of `CtxCommand`s into `AbsCommand`s.
 pub fn transform_cmds(
     cmds: &[CtxCommand],
     map: &Map,
+    graph: &RoadGraph,
     pose: &Pose,
 ) -> Result<Vec<AbsoluteCommand>, CtxCommand> {
     let mut curr_pose = *pose;
     let mut abs_cmds = vec![];
 
     for cmd in cmds {
-        let mut next_abs_cmds = transform_cmd(cmd, map, &curr_pose).ok_or(*cmd)?;
+        let mut next_abs_cmds =
+            transform_cmd(cmd, map, graph, &curr_pose).ok_or_else(|| cmd.clone())?;
         curr_pose = curr_pose.apply_cmds(&next_abs_cmds);
         abs_cmds.append(&mut next_abs_cmds);
     }
@@ -25,25 +32,75 @@ pub fn transform_cmds(
     Ok(abs_cmds)
 }
 
-/// Uses the context of the `Map` and the player's `Pose` to transform a
-/// `CtxCommand` into an `AbsCommand`.
-pub fn transform_cmd(cmd: &CtxCommand, map: &Map, pose: &Pose) -> Option<Vec<AbsoluteCommand>> {
+/// Uses the context of the `Map`, its `RoadGraph` and the player's `Pose` to
+/// transform a `CtxCommand` into an `AbsCommand`.
+pub fn transform_cmd(
+    cmd: &CtxCommand,
+    map: &Map,
+    graph: &RoadGraph,
+    pose: &Pose,
+) -> Option<Vec<AbsoluteCommand>> {
     match cmd {
         CtxCommand::Forward(CtxCommandDistance::ThisOrNextStreet(dir)) => {
-            if at_intersection(map, pose, *dir) {
+            if at_intersection(graph, pose, *dir) {
                 // The pose is already at the destination intersection, so no
                 // forward command is need.
                 Some(vec![])
             } else {
-                let dist = dist_to_nth_street(map, pose, 1, *dir)?;
+                let dist = dist_to_nth_street(graph, pose, 1, *dir)?;
                 Some(vec![AbsoluteCommand::Forward(dist)])
             }
         }
         CtxCommand::Forward(CtxCommandDistance::NthStreet(n, dir)) => {
-            let dist = dist_to_nth_street(map, pose, *n, *dir)?;
+            let dist = dist_to_nth_street(graph, pose, *n, *dir)?;
+            Some(vec![AbsoluteCommand::Forward(dist)])
+        }
+        CtxCommand::Forward(CtxCommandDistance::PastNthStreet(n)) => {
+            // The street only counts as crossed once the player has stepped
+            // off the intersection on the far side.
+            let dist = dist_to_nth_street(graph, pose, *n, None)?;
+            pose.move_forward_unsafe(dist).step_forward(map)?;
+            Some(vec![AbsoluteCommand::Forward(dist + 1)])
+        }
+        CtxCommand::Forward(CtxCommandDistance::LastStreet(dir)) => {
+            let dist = dist_to_last_street(graph, pose, *dir)?;
             Some(vec![AbsoluteCommand::Forward(dist)])
         }
+        CtxCommand::Forward(CtxCommandDistance::NamedStreet(name, dir)) => {
+            match dist_to_named_street(graph, pose, name, *dir)? {
+                0 => Some(vec![]),
+                dist => Some(vec![AbsoluteCommand::Forward(dist)]),
+            }
+        }
+        CtxCommand::Forward(CtxCommandDistance::Landmark(name, dir)) => {
+            match dist_to_landmark(map, graph, pose, name, *dir)? {
+                0 => Some(vec![]),
+                dist => Some(vec![AbsoluteCommand::Forward(dist)]),
+            }
+        }
+        CtxCommand::Forward(CtxCommandDistance::PastLandmark(name, dir)) => {
+            match dist_past_landmark(map, graph, pose, name, *dir)? {
+                0 => Some(vec![]),
+                dist => Some(vec![AbsoluteCommand::Forward(dist)]),
+            }
+        }
+        CtxCommand::Forward(CtxCommandDistance::EndOfStreet) => {
+            match dist_to_end_of_street(graph, pose) {
+                0 => Some(vec![]),
+                dist => Some(vec![AbsoluteCommand::Forward(dist)]),
+            }
+        }
         CtxCommand::Rotate(dir) => Some(vec![AbsoluteCommand::Rotate(*dir)]),
+        CtxCommand::Face(dir) => {
+            let rotation = [
+                TurnDirection::Left,
+                TurnDirection::Right,
+                TurnDirection::Around,
+            ]
+            .into_iter()
+            .find(|turn_dir| pose.direction().turn(*turn_dir) == *dir);
+            Some(rotation.map(AbsoluteCommand::Rotate).into_iter().collect())
+        }
     }
 }
 
@@ -53,17 +110,17 @@ pub fn transform_cmd(cmd: &CtxCommand, map: &Map, pose: &Pose) -> Option<Vec<Abs
 ///
 /// If no `TurnDirection` is given, this simply returns `true` if the player is
 /// at an interestion with a street from any direction.
-fn at_intersection(map: &Map, pose: &Pose, dir: Option<TurnDirection>) -> bool {
+fn at_intersection(graph: &RoadGraph, pose: &Pose, dir: Option<TurnDirection>) -> bool {
     let turn_dirs = match dir {
         Some(dir) => vec![dir],
         None => vec![TurnDirection::Left, TurnDirection::Right],
     };
 
-    turn_dirs
-        .iter()
-        .map(|d| pose.direction().turn(*d))
-        .filter_map(|d| map.get_neighbor(pose.position(), d))
-        .any(|c| c.is_road())
+    graph.node_at(pose.position()).is_some_and(|node| {
+        turn_dirs
+            .iter()
+            .any(|d| node.has_exit(pose.direction().turn(*d)))
+    })
 }
 
 /// Finds the distance to the `n`th street after the player's current position
@@ -73,35 +130,440 @@ fn at_intersection(map: &Map, pose: &Pose, dir: Option<TurnDirection>) -> bool {
 /// If no `TurnDirection` is given, this simply returns the distance to `n`th
 /// intersection after the player's current position.
 fn dist_to_nth_street(
-    map: &Map,
+    graph: &RoadGraph,
     pose: &Pose,
     n: usize,
     dir: Option<TurnDirection>,
 ) -> Option<usize> {
-    let map_size = map.size();
-    let mut curr_pose = *pose;
-    let mut streets_count = 0;
-    let mut dist = 0;
+    if n == 0 {
+        return Some(0);
+    }
 
     let turn_dirs = match dir {
         Some(dir) => vec![dir],
         None => vec![TurnDirection::Left, TurnDirection::Right],
     };
 
-    while streets_count < n {
-        dist += 1;
-        curr_pose = curr_pose.step_forward(map)?;
+    graph
+        .nodes_ahead(pose)
+        .into_iter()
+        .filter(|(_, node)| {
+            turn_dirs
+                .iter()
+                .any(|d| node.has_exit(pose.direction().turn(*d)))
+        })
+        .nth(n - 1)
+        .map(|(dist, _)| dist)
+}
 
-        let found_round = turn_dirs
+/// Finds the distance to the last street before the end of the street the
+/// player is on, in the given `TurnDirection` relative to the player's current
+/// orientation.
+///
+/// If no `TurnDirection` is given, this returns the distance to the last
+/// intersection before the street ends.
+fn dist_to_last_street(
+    graph: &RoadGraph,
+    pose: &Pose,
+    dir: Option<TurnDirection>,
+) -> Option<usize> {
+    let turn_dirs = match dir {
+        Some(dir) => vec![dir],
+        None => vec![TurnDirection::Left, TurnDirection::Right],
+    };
+
+    graph
+        .nodes_ahead(pose)
+        .into_iter()
+        .rev()
+        .find(|(_, node)| {
+            turn_dirs
+                .iter()
+                .any(|d| node.has_exit(pose.direction().turn(*d)))
+        })
+        .map(|(dist, _)| dist)
+}
+
+/// Finds the distance to the street with the given name, starting from and
+/// including the player's current position.
+///
+/// If a `TurnDirection` is given, the named street must be the one that
+/// intersects in that direction relative to the player's current orientation.
+/// Otherwise, the player may be standing on the named street, passing an
+/// intersection with it on either side or walking onto it straight ahead.
+fn dist_to_named_street(
+    graph: &RoadGraph,
+    pose: &Pose,
+    name: &str,
+    dir: Option<TurnDirection>,
+) -> Option<usize> {
+    let is_named = |edge: &Edge| edge.name().is_some_and(|n| names_match(n, name));
+    if dir.is_none() && graph.edges_at(pose.position()).into_iter().any(is_named) {
+        return Some(0);
+    }
+
+    let exit_dirs = match dir {
+        Some(dir) => vec![pose.direction().turn(dir)],
+        None => vec![
+            pose.direction().turn(TurnDirection::Left),
+            pose.direction().turn(TurnDirection::Right),
+            pose.direction(),
+        ],
+    };
+
+    graph
+        .node_at(pose.position())
+        .map(|node| (0, node))
+        .into_iter()
+        .chain(graph.nodes_ahead(pose))
+        .find(|(_, node)| {
+            exit_dirs
+                .iter()
+                .filter_map(|d| node.exit(*d))
+                .any(|id| is_named(graph.edge(id)))
+        })
+        .map(|(dist, _)| dist)
+}
+
+/// Finds the distance to the last position the player can step forward to
+/// before the street ends.
+fn dist_to_end_of_street(graph: &RoadGraph, pose: &Pose) -> usize {
+    graph.nodes_ahead(pose).last().map_or(0, |(dist, _)| *dist)
+}
+
+/// Finds the distance to the first position, starting from and including the
+/// player's current position, that is beside the building with the given name.
+///
+/// If a `TurnDirection` is given, the building must be on that side of the
+/// player. Otherwise, it may be on either side.
+fn dist_to_landmark(
+    map: &Map,
+    graph: &RoadGraph,
+    pose: &Pose,
+    name: &str,
+    dir: Option<TurnDirection>,
+) -> Option<usize> {
+    landmark_dists(map, graph, pose, name, dir).first().copied()
+}
+
+/// Finds the distance to the first street in the given `TurnDirection` after
+/// the player has walked past the building with the given name.
+fn dist_past_landmark(
+    map: &Map,
+    graph: &RoadGraph,
+    pose: &Pose,
+    name: &str,
+    dir: TurnDirection,
+) -> Option<usize> {
+    // The landmark is behind the player one step after the last position of
+    // the first unbroken stretch beside it.
+    let dists = landmark_dists(map, graph, pose, name, None);
+    let first = *dists.first()?;
+    let past = first
+        + dists
             .iter()
-            .map(|d| curr_pose.direction().turn(*d))
-            .filter_map(|d| map.get_neighbor(curr_pose.position(), d))
-            .any(|c| c.is_road());
+            .zip(first..)
+            .take_while(|(d, i)| **d == *i)
+            .count();
 
-        if found_round {
-            streets_count += 1;
-        }
+    graph
+        .nodes_ahead(pose)
+        .into_iter()
+        .find(|(dist, node)| *dist >= past && node.has_exit(pose.direction().turn(dir)))
+        .map(|(dist, _)| dist)
+}
+
+/// Returns the distance to each position ahead of the player, starting from
+/// and including the player's current position, that is beside the building
+/// with the given name in the given `TurnDirection` relative to the player's
+/// current orientation.
+///
+/// If no `TurnDirection` is given, the building may be on either side.
+fn landmark_dists(
+    map: &Map,
+    graph: &RoadGraph,
+    pose: &Pose,
+    name: &str,
+    dir: Option<TurnDirection>,
+) -> Vec<usize> {
+    let sides = match dir {
+        Some(dir) => vec![pose.direction().turn(dir)],
+        None => vec![
+            pose.direction().turn(TurnDirection::Left),
+            pose.direction().turn(TurnDirection::Right),
+        ],
+    };
+
+    graph
+        .buildings_ahead(pose)
+        .into_iter()
+        .filter(|(_, side, id)| {
+            sides.contains(side)
+                && map
+                    .building(*id)
+                    .and_then(|b| b.name())
+                    .is_some_and(|n| names_match(n, name))
+        })
+        .map(|(dist, _, _)| dist)
+        .dedup()
+        .collect()
+}
+
+/// Returns `true` if the two names are the same, ignoring case and spacing.
+fn names_match(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| s.split_whitespace().join(" ").to_lowercase();
+    normalize(a) == normalize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transform_cmds;
+    use crate::cmd::{AbsoluteCommand, CtxCommand};
+    use crate::direction::{CardinalDirection, TurnDirection};
+    use crate::lang::{Directions, Lexeme};
+    use crate::map::{fixture, Map, RoadGraph};
+    use crate::pose::Pose;
+
+    /// Parses the line and transforms it into commands from the given pose.
+    fn transform(map: &Map, pose: &Pose, line: &str) -> Option<Vec<AbsoluteCommand>> {
+        let lexemes = Lexeme::parse_line(line).unwrap();
+        let ctx_cmds: Vec<CtxCommand> = Directions::parse(&lexemes).unwrap().into();
+        transform_cmds(&ctx_cmds, map, &RoadGraph::new(map), pose).ok()
     }
 
-    Some(dist)
+    #[test]
+    fn transform_end_of_street() {
+        // A street crosses the avenue at 4, and the avenue ends at 8.
+        let map = fixture::map(&["ns,3,0,9,0,Avenida 1", "ew,0,4,7,1,"], &[]);
+        let pose = Pose::new(3, 0, CardinalDirection::North);
+
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta el final de la calle"),
+            Some(vec![AbsoluteCommand::Forward(8)])
+        );
+        assert_eq!(
+            transform(&map, &pose, "gira a la izquierda al final de la calle"),
+            Some(vec![
+                AbsoluteCommand::Forward(8),
+                AbsoluteCommand::Rotate(TurnDirection::Left)
+            ])
+        );
+
+        // At the end of the street there is nowhere left to go.
+        let end = Pose::new(3, 8, CardinalDirection::North);
+        assert_eq!(
+            transform(&map, &end, "continúa hasta el final de la calle"),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn transform_face() {
+        let map = fixture::map(&["ns,3,0,9,0,Avenida 1"], &[]);
+        let pose = Pose::new(3, 4, CardinalDirection::North);
+        let graph = RoadGraph::new(&map);
+        let face = |dir| transform_cmds(&[CtxCommand::Face(dir)], &map, &graph, &pose).unwrap();
+
+        assert_eq!(face(CardinalDirection::North), vec![]);
+        assert_eq!(
+            face(CardinalDirection::West),
+            vec![AbsoluteCommand::Rotate(TurnDirection::Left)]
+        );
+        assert_eq!(
+            face(CardinalDirection::East),
+            vec![AbsoluteCommand::Rotate(TurnDirection::Right)]
+        );
+        assert_eq!(
+            face(CardinalDirection::South),
+            vec![AbsoluteCommand::Rotate(TurnDirection::Around)]
+        );
+        assert_eq!(
+            transform(&map, &pose, "ve al sur"),
+            Some(vec![AbsoluteCommand::Rotate(TurnDirection::Around)])
+        );
+    }
+
+    #[test]
+    fn transform_u_turns() {
+        let map = fixture::map(&["ns,3,0,9,0,Avenida 1"], &[]);
+        let pose = Pose::new(3, 4, CardinalDirection::North);
+        let around = vec![AbsoluteCommand::Rotate(TurnDirection::Around)];
+
+        assert_eq!(transform(&map, &pose, "da la vuelta"), Some(around.clone()));
+        assert_eq!(
+            transform(&map, &pose, "da media vuelta"),
+            Some(around.clone())
+        );
+        assert_eq!(
+            transform(&map, &pose, "regresa por donde viniste"),
+            Some(around.clone())
+        );
+        assert_eq!(
+            pose.apply_cmds(&around),
+            Pose::new(3, 4, CardinalDirection::South)
+        );
+
+        // After turning around, the end of the street is back at the start.
+        assert_eq!(
+            transform(
+                &map,
+                &pose,
+                "da la vuelta y luego continúa hasta el final de la calle"
+            ),
+            Some(vec![
+                AbsoluteCommand::Rotate(TurnDirection::Around),
+                AbsoluteCommand::Forward(4)
+            ])
+        );
+    }
+
+    #[test]
+    fn transform_última_and_próxima() {
+        // Streets cross the avenue on the right at 2, 4 and 6, and on the left
+        // at 4.
+        let map = fixture::map(
+            &[
+                "ns,3,0,9,0,Avenida 1",
+                "ew,3,2,4,1,",
+                "ew,0,4,7,1,",
+                "ew,3,6,4,1,",
+            ],
+            &[],
+        );
+        let pose = Pose::new(3, 0, CardinalDirection::North);
+        let transform = |line| transform(&map, &pose, line).unwrap();
+        let right = AbsoluteCommand::Rotate(TurnDirection::Right);
+        let left = AbsoluteCommand::Rotate(TurnDirection::Left);
+
+        assert_eq!(
+            transform("toma la última calle a la derecha"),
+            vec![AbsoluteCommand::Forward(6), right]
+        );
+        assert_eq!(
+            transform("toma la última calle a la izquierda"),
+            vec![AbsoluteCommand::Forward(4), left]
+        );
+        assert_eq!(
+            transform("toma la calle siguiente a la derecha"),
+            vec![AbsoluteCommand::Forward(2), right]
+        );
+        assert_eq!(
+            transform("toma la próxima calle a la izquierda"),
+            vec![AbsoluteCommand::Forward(4), left]
+        );
+    }
+
+    #[test]
+    fn transform_synonym_verbs() {
+        // Streets cross the avenue at 2, 4 and 6.
+        let map = fixture::map(
+            &[
+                "ns,3,0,9,0,Avenida 1",
+                "ew,0,2,7,1,",
+                "ew,0,4,7,1,",
+                "ew,0,6,7,1,",
+            ],
+            &[],
+        );
+        let pose = Pose::new(3, 0, CardinalDirection::North);
+        let transform = |line| transform(&map, &pose, line);
+
+        let two_blocks = Some(vec![AbsoluteCommand::Forward(4)]);
+        assert_eq!(transform("sigue todo derecho dos quadras"), two_blocks);
+        assert_eq!(transform("camina dos quadras"), two_blocks);
+        assert_eq!(transform("ve derecho dos calles"), two_blocks);
+        assert_eq!(
+            transform("dobla la segunda calle a la derecha"),
+            Some(vec![
+                AbsoluteCommand::Forward(4),
+                AbsoluteCommand::Rotate(TurnDirection::Right)
+            ])
+        );
+
+        // Crossing a street ends just past it, so the next street is the one
+        // after.
+        let crossed = Some(vec![AbsoluteCommand::Forward(5)]);
+        assert_eq!(transform("cruza dos calles"), crossed);
+        assert_eq!(transform("pasa dos calles"), crossed);
+        assert_eq!(
+            transform("cruza una calle y luego gira a la derecha"),
+            Some(vec![
+                AbsoluteCommand::Forward(3),
+                AbsoluteCommand::Forward(1),
+                AbsoluteCommand::Rotate(TurnDirection::Right)
+            ])
+        );
+        assert_eq!(transform("cruza 4 calles"), None);
+    }
+
+    #[test]
+    fn transform_named_streets_and_landmarks() {
+        // Calle 1 crosses the avenue at 2 and Calle 2 meets it on the right at
+        // 6. The park is on the right at 3 and 4, and the house on the left
+        // at 7.
+        let map = fixture::map(
+            &[
+                "ns,3,0,9,0,Avenida 1",
+                "ew,0,2,7,1,Calle 1",
+                "ew,3,6,4,1,Calle 2",
+            ],
+            &["4,3,2,2,el parque", "2,7,1,1,la casa"],
+        );
+        let pose = Pose::new(3, 0, CardinalDirection::North);
+        let forward = |dist| Some(vec![AbsoluteCommand::Forward(dist)]);
+
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta la Calle 1"),
+            forward(2)
+        );
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta la Calle 2"),
+            forward(6)
+        );
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta la Calle 2 a la derecha"),
+            forward(6)
+        );
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta la Calle 2 a la izquierda"),
+            None
+        );
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta la Avenida 1"),
+            Some(vec![])
+        );
+        let on_calle_1 = Pose::new(3, 2, CardinalDirection::North);
+        assert_eq!(
+            transform(&map, &on_calle_1, "continúa hasta la Calle 1"),
+            Some(vec![])
+        );
+
+        assert_eq!(
+            transform(&map, &pose, "continúa hasta el parque"),
+            forward(3)
+        );
+        assert_eq!(transform(&map, &pose, "continúa hasta la casa"), forward(7));
+        let by_park = Pose::new(3, 4, CardinalDirection::North);
+        assert_eq!(
+            transform(&map, &by_park, "continúa hasta el parque"),
+            Some(vec![])
+        );
+
+        // The first street on the right once the park is behind is Calle 2.
+        assert_eq!(
+            transform(&map, &pose, "gira a la derecha después del parque"),
+            Some(vec![
+                AbsoluteCommand::Forward(6),
+                AbsoluteCommand::Rotate(TurnDirection::Right)
+            ])
+        );
+        assert_eq!(
+            transform(&map, &pose, "gira a la izquierda después del parque"),
+            None
+        );
+        assert_eq!(
+            transform(&map, &pose, "gira a la izquierda después de la casa"),
+            None
+        );
+    }
 }
@@ -0,0 +1,206 @@
+//! This module turns a route back into directions in Spanish, the reverse of
+//! parsing a sentence and transforming it into commands.
+
+use iter_tools::Itertools;
+use std::num::NonZeroUsize;
+
+use crate::cmd::{transform_cmds, AbsoluteCommand, CtxCommand};
+use crate::direction::TurnDirection;
+use crate::lang::syntax::{
+    DistanceNounPhrase, EndOfStreetNounPhrase, ForwardNounPhrase, Gender, LandmarkNounPhrase,
+    LeftRightTurnPrepPhrase, Ordinality, StreetNounPhrase, TurnDirectionNoun,
+    TurnDirectionNounPhrase, TurnableNounPhrase, UTurnNounPhrase,
+};
+use crate::lang::{Directions, Sentence};
+use crate::map::{BuildingId, Map, RoadGraph};
+use crate::pose::Pose;
+
+/// Describes the route taken from the starting pose to the goal by the given
+/// commands, or returns `None` if part of the route cannot be put into words.
+///
+/// Each part of the route is described by the first sentence that, when
+/// transformed back into commands, leaves the player in the same pose. The
+/// route may stop diagonally beside the goal where no sentence stops, so the
+/// last part may instead end anywhere beside the goal.
+pub fn describe(
+    map: &Map,
+    graph: &RoadGraph,
+    start: &Pose,
+    cmds: &[AbsoluteCommand],
+    goal: BuildingId,
+) -> Option<Directions> {
+    let landmarks = landmarks(map);
+    let mut pose = *start;
+    let mut sentences = vec![];
+    let mut rest = cmds;
+
+    while let [first, next @ ..] = rest {
+        let (len, candidates) = match (first, next.first()) {
+            (AbsoluteCommand::Rotate(TurnDirection::Around), _) => {
+                (1, vec![Sentence::DaNp(UTurnNounPhrase::LaVuelta)])
+            }
+            (AbsoluteCommand::Rotate(dir), _) => (1, turn_sentences(0, *dir)),
+            (AbsoluteCommand::Forward(dist), Some(AbsoluteCommand::Rotate(dir)))
+                if *dir != TurnDirection::Around =>
+            {
+                (2, turn_sentences(*dist, *dir))
+            }
+            (AbsoluteCommand::Forward(_), _) => (1, forward_sentences(graph, &pose, &landmarks)),
+        };
+
+        let (segment, after) = rest.split_at(len);
+        let target = pose.apply_cmds(segment);
+        let (sentence, end) = candidates.into_iter().find_map(|s| {
+            let end = follow(map, graph, &pose, &s)?;
+            let at_goal = after.is_empty()
+                && end.direction() == target.direction()
+                && map.is_beside(end.position(), goal);
+            (end == target || at_goal).then_some((s, end))
+        })?;
+
+        sentences.push(sentence);
+        pose = end;
+        rest = after;
+    }
+
+    Some(Directions(sentences))
+}
+
+/// Renders the directions as text, one capitalized sentence after another.
+pub fn render(directions: &Directions) -> String {
+    directions
+        .0
+        .iter()
+        .map(|s| {
+            let text = s.to_string();
+            let mut chars = text.chars();
+            match chars.next() {
+                Some(c) => format!("{}{}.", c.to_uppercase(), chars.as_str()),
+                None => text,
+            }
+        })
+        .join(" ")
+}
+
+/// Returns the sentences that might describe walking forward the given
+/// distance and then turning.
+fn turn_sentences(dist: usize, dir: TurnDirection) -> Vec<Sentence> {
+    let Some(pp) = turn_pp(dir) else {
+        return vec![];
+    };
+    let mut sentences = vec![];
+    if dist > 0 {
+        sentences.extend(
+            (1..)
+                .map_while(Ordinality::new_fem)
+                .chain([Ordinality::last(Gender::Feminine)])
+                .map(|ord| {
+                    let np = TurnableNounPhrase::Street(StreetNounPhrase::LaCalleOrd(ord));
+                    Sentence::TomaNpPp(np, pp)
+                }),
+        );
+        sentences.push(Sentence::GiraPpAlFinalNp(pp, EndOfStreetNounPhrase()));
+    }
+    sentences.push(Sentence::GiraPp(pp));
+    sentences
+}
+
+/// Returns the sentences that might describe walking straight ahead from the
+/// given pose.
+fn forward_sentences(
+    graph: &RoadGraph,
+    pose: &Pose,
+    landmarks: &[LandmarkNounPhrase],
+) -> Vec<Sentence> {
+    // Landmarks come first since they are the most natural way to describe
+    // the last part of a route.
+    let f_np = ForwardNounPhrase();
+    let mut sentences = landmarks
+        .iter()
+        .map(|np| Sentence::ContinúaNpHastaLandmarkNp(Some(f_np), *np))
+        .collect_vec();
+    sentences.extend(
+        (1..=intersections_ahead(graph, pose))
+            .filter_map(NonZeroUsize::new)
+            .map(|n| Sentence::ContinúaNpNp(f_np, DistanceNounPhrase::NQuadras(n))),
+    );
+    sentences.push(Sentence::ContinúaNpHastaFinalNp(
+        Some(f_np),
+        EndOfStreetNounPhrase(),
+    ));
+    sentences
+}
+
+/// Returns the number of intersections ahead of the player with a street on
+/// either side, which is the most blocks that a sentence could need to count.
+fn intersections_ahead(graph: &RoadGraph, pose: &Pose) -> usize {
+    let sides = [TurnDirection::Left, TurnDirection::Right].map(|d| pose.direction().turn(d));
+    graph
+        .nodes_ahead(pose)
+        .into_iter()
+        .filter(|(_, node)| sides.iter().any(|side| node.has_exit(*side)))
+        .count()
+}
+
+/// Returns the noun phrases for every building whose name can be used as a
+/// landmark.
+fn landmarks(map: &Map) -> Vec<LandmarkNounPhrase> {
+    map.buildings()
+        .iter()
+        .filter_map(|b| LandmarkNounPhrase::from_name(b.name()?))
+        .unique()
+        .collect()
+}
+
+/// Returns the phrase naming the side of a turn, or `None` for a U-turn, which
+/// has no side.
+fn turn_pp(dir: TurnDirection) -> Option<LeftRightTurnPrepPhrase> {
+    let noun = match dir {
+        TurnDirection::Left => TurnDirectionNoun::Izquierda,
+        TurnDirection::Right => TurnDirectionNoun::Derecha,
+        TurnDirection::Around => return None,
+    };
+    Some(LeftRightTurnPrepPhrase(TurnDirectionNounPhrase(noun)))
+}
+
+/// Returns the pose at which following the sentence from the given pose ends.
+fn follow(map: &Map, graph: &RoadGraph, pose: &Pose, sentence: &Sentence) -> Option<Pose> {
+    let ctx_cmds = Vec::<CtxCommand>::from(sentence.clone());
+    let cmds = transform_cmds(&ctx_cmds, map, graph, pose).ok()?;
+    Some(pose.apply_cmds(&cmds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, intersections_ahead, render};
+    use crate::direction::CardinalDirection;
+    use crate::map::{fixture, BuildingId, RoadGraph};
+    use crate::pose::Pose;
+    use crate::solve::solve;
+
+    #[test]
+    fn describe_solved_route() {
+        let map = fixture::map(
+            &[
+                "ns,2,0,9,1,Carrera 1",
+                "ew,0,2,9,0,Calle 1",
+                "ew,0,5,9,0,Calle 2",
+            ],
+            &["6,6,2,2,el hospital", "0,0,2,2,la casa"],
+        );
+
+        let start = Pose::new(2, 0, CardinalDirection::North);
+        let goal = BuildingId::new(0);
+        let route = solve(&map, &start, goal).unwrap();
+        let graph = RoadGraph::new(&map);
+        let directions = describe(&map, &graph, &start, &route.cmds, goal).unwrap();
+        assert_eq!(
+            render(&directions),
+            "Toma la segunda calle a la derecha. Continúa todo derecho hasta el hospital."
+        );
+
+        // Only the two streets ahead can be counted in blocks, however long
+        // the road is.
+        assert_eq!(intersections_ahead(&graph, &start), 2);
+    }
+}
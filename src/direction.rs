@@ -1,6 +1,8 @@
+use serde::Deserialize;
 use std::fmt::Display;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CardinalDirection {
     North,
     East,
@@ -9,11 +11,22 @@ pub enum CardinalDirection {
 }
 
 impl CardinalDirection {
+    /// Returns every cardinal direction.
+    pub fn all() -> [Self; 4] {
+        [
+            CardinalDirection::North,
+            CardinalDirection::East,
+            CardinalDirection::South,
+            CardinalDirection::West,
+        ]
+    }
+
     /// Returns the new cardinal direction after turning..
     pub fn turn(&self, dir: TurnDirection) -> Self {
         match dir {
             TurnDirection::Left => self.left(),
             TurnDirection::Right => self.right(),
+            TurnDirection::Around => self.opposite(),
         }
     }
 
@@ -27,6 +40,16 @@ impl CardinalDirection {
         }
     }
 
+    /// Returns the new cardinal direction after turning around.
+    pub fn opposite(&self) -> Self {
+        match self {
+            CardinalDirection::North => CardinalDirection::South,
+            CardinalDirection::East => CardinalDirection::West,
+            CardinalDirection::South => CardinalDirection::North,
+            CardinalDirection::West => CardinalDirection::East,
+        }
+    }
+
     /// Returns the new cardinal direction after turning to the left.
     pub fn left(&self) -> Self {
         match self {
@@ -54,6 +77,9 @@ impl Display for CardinalDirection {
 pub enum TurnDirection {
     Left,
     Right,
+
+    /// A U-turn.
+    Around,
 }
 
 impl Display for TurnDirection {
@@ -61,6 +87,7 @@ impl Display for TurnDirection {
         let s = match self {
             Self::Left => "left",
             Self::Right => "right",
+            Self::Around => "around",
         };
         write!(f, "{s}")
     }
@@ -41,6 +41,16 @@ where
         }
     }
 
+    pub fn neighbor_index(&self, idx: Vec2<usize>, dir: CardinalDirection) -> Option<Vec2<usize>> {
+        let neighbors = self.neighbor_indices(idx);
+        match dir {
+            CardinalDirection::North => neighbors.n,
+            CardinalDirection::East => neighbors.e,
+            CardinalDirection::South => neighbors.s,
+            CardinalDirection::West => neighbors.w,
+        }
+    }
+
     pub fn inner_grid(&self) -> &InnerGrid<T> {
         &self.0
     }
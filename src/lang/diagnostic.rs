@@ -0,0 +1,102 @@
+//! This module renders parse errors against the line of text they were read
+//! from, so that a learner can see which words caused the error.
+
+use std::{error::Error, fmt::Display};
+
+use super::{syntax::Span, DirectionsParseError, Lexeme};
+
+/// A parse error located within the line of text it was read from.
+///
+/// Example:
+/// ```text
+/// toma la primero calle a la derecha
+///         ^^^^^^^^^^^^^
+/// "primero" is masculine, but "calle" is feminine. Use "primera" instead.
+/// ```
+#[derive(Debug)]
+pub struct Diagnostic<'a> {
+    line: &'a str,
+    span: Span,
+    message: String,
+}
+
+impl<'a> Diagnostic<'a> {
+    pub fn new(line: &'a str, err: &DirectionsParseError) -> Self {
+        Self {
+            line,
+            span: err.span(),
+            message: innermost_source(err).to_string(),
+        }
+    }
+
+    /// Returns the char column at which the underline starts and its width.
+    fn underline(&self) -> (usize, usize) {
+        let ranges = Lexeme::word_ranges(self.line);
+        let column = |byte: usize| self.line[..byte].chars().count();
+
+        // A span past the last word points at the missing words just after
+        // the end of the line.
+        let Some(first) = ranges.get(self.span.start) else {
+            let end = ranges.last().map_or(0, |r| column(r.end) + 1);
+            return (end, 1);
+        };
+
+        let last = ranges
+            .get(self.span.end.saturating_sub(1))
+            .filter(|_| self.span.end > self.span.start)
+            .unwrap_or(first);
+        let start = column(first.start);
+        let end = column(last.end.max(first.end));
+        (start, (end - start).max(1))
+    }
+}
+
+impl Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (start, width) = self.underline();
+        writeln!(f, "{}", self.line)?;
+        writeln!(f, "{}{}", " ".repeat(start), "^".repeat(width))?;
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Returns the most specific error in the chain of sources. Outer errors only
+/// locate the failing phrase, so the innermost one carries the explanation.
+fn innermost_source<'a>(err: &'a (dyn Error + 'static)) -> &'a (dyn Error + 'static) {
+    let mut err = err;
+    while let Some(source) = err.source() {
+        err = source;
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Diagnostic;
+    use crate::lang::{Directions, Lexeme};
+
+    #[test]
+    fn underline_gender_disagreement() {
+        let line = "toma la primero calle a la derecha";
+        let lexemes = Lexeme::parse_line(line).unwrap();
+        let err = Directions::parse(&lexemes).unwrap_err();
+        let rendered = Diagnostic::new(line, &err).to_string();
+
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next(), Some(line));
+        assert_eq!(lines.next(), Some("        ^^^^^^^^^^^^^"));
+        assert_eq!(
+            lines.next(),
+            Some(r#""primero" is masculine, but "calle" is feminine. Use "primera" instead."#)
+        );
+    }
+
+    #[test]
+    fn underline_past_end_of_line() {
+        let line = "gira a";
+        let lexemes = Lexeme::parse_line(line).unwrap();
+        let err = Directions::parse(&lexemes).unwrap_err();
+        let rendered = Diagnostic::new(line, &err).to_string();
+        assert_eq!(rendered.lines().nth(1), Some("       ^"));
+    }
+}
@@ -2,11 +2,16 @@
 //! text.
 
 use iter_tools::Itertools;
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, ops::Range, str::FromStr};
 use thiserror::Error;
 
+use super::{
+    spelling,
+    token::{tokenize, Accents},
+};
+
 /// A lexeme or word that can be used to build a sentence.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Lexeme {
     /// "first" (feminine)
     Primera,
@@ -32,6 +37,57 @@ pub enum Lexeme {
     /// "second" (masculine)
     Cuarto,
 
+    /// "fifth" (feminine)
+    Quinta,
+
+    /// "fifth" (masculine)
+    Quinto,
+
+    /// "sixth" (feminine)
+    Sexta,
+
+    /// "sixth" (masculine)
+    Sexto,
+
+    /// "seventh" (feminine)
+    Séptima,
+
+    /// "seventh" (masculine)
+    Séptimo,
+
+    /// "eighth" (feminine)
+    Octava,
+
+    /// "eighth" (masculine)
+    Octavo,
+
+    /// "ninth" (feminine)
+    Novena,
+
+    /// "ninth" (masculine)
+    Noveno,
+
+    /// "tenth" (feminine)
+    Décima,
+
+    /// "tenth" (masculine)
+    Décimo,
+
+    /// "last" (feminine)
+    Última,
+
+    /// "last" (masculine)
+    Último,
+
+    /// "next" (feminine)
+    Próxima,
+
+    /// "next" (masculine)
+    Próximo,
+
+    /// "next"
+    Siguiente,
+
     /// "in"
     En,
 
@@ -44,6 +100,9 @@ pub enum Lexeme {
     // "to the" (masculine)
     Al,
 
+    /// "of the" (masculine)
+    Del,
+
     /// "the" (femenine)
     La,
 
@@ -62,6 +121,82 @@ pub enum Lexeme {
     // "street"
     Calle,
 
+    /// "road" (names streets that run perpendicular to "calles")
+    Carrera,
+
+    /// "avenue"
+    Avenida,
+
+    /// "highway"
+    Autopista,
+
+    /// "north"
+    Norte,
+
+    /// "south"
+    Sur,
+
+    /// "east"
+    Este,
+
+    /// "west"
+    Oeste,
+
+    /// "towards"
+    Hacia,
+
+    /// "go" (imperative)
+    Ve,
+
+    /// A number written with digits, as in "Calle 87", "Carrera 19b" or
+    /// "7 quadras".
+    Numeral(Numeral),
+
+    /// "house"
+    Casa,
+
+    /// "store"
+    Tienda,
+
+    /// "hospital"
+    Hospital,
+
+    /// "park"
+    Parque,
+
+    /// "supermarket"
+    Supermercado,
+
+    /// "hotel"
+    Hotel,
+
+    /// "in front", "across"
+    Enfrente,
+
+    /// "you give" (imperative), as in "da la vuelta"
+    Da,
+
+    /// "half"
+    Media,
+
+    /// "turn"
+    Vuelta,
+
+    /// "go back" (imperative)
+    Regresa,
+
+    /// "by", "through"
+    Por,
+
+    /// "where"
+    Donde,
+
+    /// "you came"
+    Viniste,
+
+    /// "you came" (usted)
+    Vino,
+
     // "streets"
     Calles,
 
@@ -84,15 +219,90 @@ pub enum Lexeme {
     /// "it is"
     Está,
 
-    /// "you take" (imperitive)
+    /// "you take" (imperative)
     Toma,
 
-    /// "you turn" (imperitive)
+    /// "you turn" (imperative)
     Gira,
 
-    /// "continue" (imperitive)
+    /// "continue" (imperative)
     Continúa,
 
+    /// "keep going" (imperative)
+    Sigue,
+
+    /// "turn" (imperative)
+    Dobla,
+
+    /// "cross" (imperative)
+    Cruza,
+
+    /// "walk" (imperative)
+    Camina,
+
+    /// "go past" (imperative)
+    Pasa,
+
+    /// "take" (imperative, usted)
+    Tome,
+
+    /// "turn" (imperative, usted)
+    Gire,
+
+    /// "continue" (imperative, usted)
+    Continúe,
+
+    /// "keep going" (imperative, usted)
+    Siga,
+
+    /// "turn" (imperative, usted)
+    Doble,
+
+    /// "take" (imperative, vos)
+    Tomá,
+
+    /// "turn" (imperative, vos)
+    Girá,
+
+    /// "keep going" (imperative, vos)
+    Seguí,
+
+    /// "turn" (imperative, vos)
+    Doblá,
+
+    /// "cross" (imperative, usted)
+    Cruce,
+
+    /// "walk" (imperative, usted)
+    Camine,
+
+    /// "go past" (imperative, usted)
+    Pase,
+
+    /// "give" (imperative, usted)
+    Dé,
+
+    /// "go back" (imperative, usted)
+    Regrese,
+
+    /// "go" (imperative, usted)
+    Vaya,
+
+    /// "cross" (imperative, vos)
+    Cruzá,
+
+    /// "walk" (imperative, vos)
+    Caminá,
+
+    /// "go past" (imperative, vos)
+    Pasá,
+
+    /// "go back" (imperative, vos)
+    Regresá,
+
+    /// "go" (imperative, vos)
+    Andá,
+
     // "a" or "one (masculine)"
     Un,
 
@@ -111,24 +321,156 @@ pub enum Lexeme {
     /// "four"
     Quatro,
 
+    /// "five"
+    Cinco,
+
+    /// "six"
+    Seis,
+
+    /// "seven"
+    Siete,
+
+    /// "eight"
+    Ocho,
+
+    /// "nine"
+    Nueve,
+
+    /// "ten"
+    Diez,
+
+    /// "eleven"
+    Once,
+
+    /// "twelve"
+    Doce,
+
+    /// "thirteen"
+    Trece,
+
+    /// "fourteen"
+    Catorce,
+
+    /// "fifteen"
+    Quince,
+
+    /// "sixteen"
+    Dieciséis,
+
+    /// "seventeen"
+    Diecisiete,
+
+    /// "eighteen"
+    Dieciocho,
+
+    /// "nineteen"
+    Diecinueve,
+
+    /// "twenty"
+    Veinte,
+
+    /// "twenty-one (masculine, before a noun)"
+    Veintiún,
+
+    /// "twenty-one (feminine)"
+    Veintiuna,
+
+    /// "twenty-one (masculine)"
+    Veintiuno,
+
+    /// "twenty-two"
+    Veintidós,
+
+    /// "twenty-three"
+    Veintitrés,
+
+    /// "twenty-four"
+    Veinticuatro,
+
+    /// "twenty-five"
+    Veinticinco,
+
+    /// "twenty-six"
+    Veintiséis,
+
+    /// "twenty-seven"
+    Veintisiete,
+
+    /// "twenty-eight"
+    Veintiocho,
+
+    /// "twenty-nine"
+    Veintinueve,
+
     // "until"
     Hasta,
+
+    /// "and"
+    Y,
+
+    /// "then", "later"
+    Luego,
+
+    /// "after", "afterwards"
+    Después,
 }
 
 impl Lexeme {
+    /// Reads the lexemes in the line, reporting words that are missing
+    /// accents as errors.
     pub fn parse_line(line: &str) -> Result<Vec<Lexeme>, LexError> {
-        let (lexemes, unknowns): (Vec<_>, Vec<_>) = line
-            .split_whitespace()
-            .map(|s| Lexeme::from_str(s).map_err(|FromStrError(s)| s))
+        Self::parse_line_with(line, Accents::default(), &Self::all())
+    }
+
+    /// Reads the lexemes in the line, ignoring punctuation. Words that are
+    /// missing accents are read according to `accents`, and unknown words are
+    /// only given suggestions from `word_bank`.
+    pub fn parse_line_with(
+        line: &str,
+        accents: Accents,
+        word_bank: &[Lexeme],
+    ) -> Result<Vec<Lexeme>, LexError> {
+        let (lexemes, errors): (Vec<_>, Vec<_>) = Self::word_ranges(line)
+            .into_iter()
+            .map(|r| Self::read_word(&line[r], accents, word_bank))
             .partition_result();
 
-        if unknowns.is_empty() {
+        if errors.is_empty() {
             Ok(lexemes)
         } else {
-            Err(LexError(unknowns))
+            Err(LexError(errors))
+        }
+    }
+
+    /// Returns the byte range of each word in the line. The nth range is the
+    /// source of the nth lexeme returned by `parse_line`.
+    pub fn word_ranges(line: &str) -> Vec<Range<usize>> {
+        tokenize(line)
+    }
+
+    fn read_word(word: &str, accents: Accents, word_bank: &[Lexeme]) -> Result<Lexeme, WordError> {
+        let err = match Lexeme::from_str(word) {
+            Ok(lexeme) => return Ok(lexeme),
+            Err(err) => err,
+        };
+        match (Self::from_folded(word), accents) {
+            (Some(lexeme), Accents::Lenient) => Ok(lexeme),
+            (Some(lexeme), Accents::Strict) => Err(WordError::Accents {
+                word: word.to_string(),
+                lexeme,
+            }),
+            (None, _) => Err(WordError::Unknown(UnknownWord::new(err, word_bank))),
         }
     }
 
+    /// Returns the lexeme that the word spells once accents are ignored.
+    fn from_folded(word: &str) -> Option<Lexeme> {
+        let folded = spelling::fold_accents(&word.to_lowercase());
+        Lexeme::all()
+            .into_iter()
+            .find(|l| spelling::fold_accents(&l.to_string()) == folded)
+    }
+
     fn from_lowercase(s: &str) -> Option<Lexeme> {
         match s {
             "en" => Some(Lexeme::En),
@@ -151,26 +493,126 @@ impl Lexeme {
             "toma" => Some(Lexeme::Toma),
             "gira" => Some(Lexeme::Gira),
             "continúa" => Some(Lexeme::Continúa),
+            "sigue" => Some(Lexeme::Sigue),
+            "dobla" => Some(Lexeme::Dobla),
+            "cruza" => Some(Lexeme::Cruza),
+            "camina" => Some(Lexeme::Camina),
+            "pasa" => Some(Lexeme::Pasa),
+            "tome" => Some(Lexeme::Tome),
+            "gire" => Some(Lexeme::Gire),
+            "continúe" => Some(Lexeme::Continúe),
+            "siga" => Some(Lexeme::Siga),
+            "doble" => Some(Lexeme::Doble),
+            "tomá" => Some(Lexeme::Tomá),
+            "girá" => Some(Lexeme::Girá),
+            "seguí" => Some(Lexeme::Seguí),
+            "doblá" => Some(Lexeme::Doblá),
+            "cruce" => Some(Lexeme::Cruce),
+            "camine" => Some(Lexeme::Camine),
+            "pase" => Some(Lexeme::Pase),
+            "dé" => Some(Lexeme::Dé),
+            "regrese" => Some(Lexeme::Regrese),
+            "vaya" => Some(Lexeme::Vaya),
+            "cruzá" => Some(Lexeme::Cruzá),
+            "caminá" => Some(Lexeme::Caminá),
+            "pasá" => Some(Lexeme::Pasá),
+            "regresá" => Some(Lexeme::Regresá),
+            "andá" => Some(Lexeme::Andá),
             "primero" => Some(Lexeme::Primero),
             "segundo" => Some(Lexeme::Segundo),
             "tercera" => Some(Lexeme::Tercera),
             "tercero" => Some(Lexeme::Tercero),
             "cuarta" => Some(Lexeme::Cuarta),
             "cuarto" => Some(Lexeme::Cuarto),
+            "quinta" => Some(Lexeme::Quinta),
+            "quinto" => Some(Lexeme::Quinto),
+            "sexta" => Some(Lexeme::Sexta),
+            "sexto" => Some(Lexeme::Sexto),
+            "séptima" => Some(Lexeme::Séptima),
+            "séptimo" => Some(Lexeme::Séptimo),
+            "octava" => Some(Lexeme::Octava),
+            "octavo" => Some(Lexeme::Octavo),
+            "novena" => Some(Lexeme::Novena),
+            "noveno" => Some(Lexeme::Noveno),
+            "décima" => Some(Lexeme::Décima),
+            "décimo" => Some(Lexeme::Décimo),
+            "última" => Some(Lexeme::Última),
+            "último" => Some(Lexeme::Último),
+            "próxima" => Some(Lexeme::Próxima),
+            "próximo" => Some(Lexeme::Próximo),
+            "siguiente" => Some(Lexeme::Siguiente),
             "un" => Some(Lexeme::Un),
             "una" => Some(Lexeme::Una),
             "uno" => Some(Lexeme::Uno),
             "dos" => Some(Lexeme::Dos),
             "tres" => Some(Lexeme::Tres),
             "quatro" => Some(Lexeme::Quatro),
+            "cinco" => Some(Lexeme::Cinco),
+            "seis" => Some(Lexeme::Seis),
+            "siete" => Some(Lexeme::Siete),
+            "ocho" => Some(Lexeme::Ocho),
+            "nueve" => Some(Lexeme::Nueve),
+            "diez" => Some(Lexeme::Diez),
+            "once" => Some(Lexeme::Once),
+            "doce" => Some(Lexeme::Doce),
+            "trece" => Some(Lexeme::Trece),
+            "catorce" => Some(Lexeme::Catorce),
+            "quince" => Some(Lexeme::Quince),
+            "dieciséis" => Some(Lexeme::Dieciséis),
+            "diecisiete" => Some(Lexeme::Diecisiete),
+            "dieciocho" => Some(Lexeme::Dieciocho),
+            "diecinueve" => Some(Lexeme::Diecinueve),
+            "veinte" => Some(Lexeme::Veinte),
+            "veintiún" => Some(Lexeme::Veintiún),
+            "veintiuna" => Some(Lexeme::Veintiuna),
+            "veintiuno" => Some(Lexeme::Veintiuno),
+            "veintidós" => Some(Lexeme::Veintidós),
+            "veintitrés" => Some(Lexeme::Veintitrés),
+            "veinticuatro" => Some(Lexeme::Veinticuatro),
+            "veinticinco" => Some(Lexeme::Veinticinco),
+            "veintiséis" => Some(Lexeme::Veintiséis),
+            "veintisiete" => Some(Lexeme::Veintisiete),
+            "veintiocho" => Some(Lexeme::Veintiocho),
+            "veintinueve" => Some(Lexeme::Veintinueve),
             "calle" => Some(Lexeme::Calle),
             "calles" => Some(Lexeme::Calles),
             "hasta" => Some(Lexeme::Hasta),
-            _ => None,
+            "carrera" => Some(Lexeme::Carrera),
+            "avenida" => Some(Lexeme::Avenida),
+            "autopista" => Some(Lexeme::Autopista),
+            "norte" => Some(Lexeme::Norte),
+            "sur" => Some(Lexeme::Sur),
+            "este" => Some(Lexeme::Este),
+            "oeste" => Some(Lexeme::Oeste),
+            "hacia" => Some(Lexeme::Hacia),
+            "ve" => Some(Lexeme::Ve),
+            "del" => Some(Lexeme::Del),
+            "casa" => Some(Lexeme::Casa),
+            "tienda" => Some(Lexeme::Tienda),
+            "hospital" => Some(Lexeme::Hospital),
+            "parque" => Some(Lexeme::Parque),
+            "supermercado" => Some(Lexeme::Supermercado),
+            "hotel" => Some(Lexeme::Hotel),
+            "enfrente" => Some(Lexeme::Enfrente),
+            "da" => Some(Lexeme::Da),
+            "media" => Some(Lexeme::Media),
+            "vuelta" => Some(Lexeme::Vuelta),
+            "regresa" => Some(Lexeme::Regresa),
+            "por" => Some(Lexeme::Por),
+            "donde" => Some(Lexeme::Donde),
+            "viniste" => Some(Lexeme::Viniste),
+            "vino" => Some(Lexeme::Vino),
+            "y" => Some(Lexeme::Y),
+            "luego" => Some(Lexeme::Luego),
+            "después" => Some(Lexeme::Después),
+            _ => Numeral::from_lowercase(s).map(Lexeme::Numeral),
         }
     }
 
     /// Returns a `Vec` containing all variants of `Lexeme`.
+    ///
+    /// Since there are infinitely many numerals, `Lexeme::Numeral` is not
+    /// included.
     pub fn all() -> Vec<Lexeme> {
         vec![
             Self::En,
@@ -193,21 +635,118 @@ impl Lexeme {
             Self::Toma,
             Self::Gira,
             Self::Continúa,
+            Self::Sigue,
+            Self::Dobla,
+            Self::Cruza,
+            Self::Camina,
+            Self::Pasa,
+            Self::Tome,
+            Self::Gire,
+            Self::Continúe,
+            Self::Siga,
+            Self::Doble,
+            Self::Tomá,
+            Self::Girá,
+            Self::Seguí,
+            Self::Doblá,
+            Self::Cruce,
+            Self::Camine,
+            Self::Pase,
+            Self::Dé,
+            Self::Regrese,
+            Self::Vaya,
+            Self::Cruzá,
+            Self::Caminá,
+            Self::Pasá,
+            Self::Regresá,
+            Self::Andá,
             Self::Primero,
             Self::Segundo,
             Self::Tercera,
             Self::Tercero,
             Self::Cuarta,
             Self::Cuarto,
+            Self::Quinta,
+            Self::Quinto,
+            Self::Sexta,
+            Self::Sexto,
+            Self::Séptima,
+            Self::Séptimo,
+            Self::Octava,
+            Self::Octavo,
+            Self::Novena,
+            Self::Noveno,
+            Self::Décima,
+            Self::Décimo,
+            Self::Última,
+            Self::Último,
+            Self::Próxima,
+            Self::Próximo,
+            Self::Siguiente,
             Self::Un,
             Self::Una,
             Self::Uno,
             Self::Dos,
             Self::Tres,
             Self::Quatro,
+            Self::Cinco,
+            Self::Seis,
+            Self::Siete,
+            Self::Ocho,
+            Self::Nueve,
+            Self::Diez,
+            Self::Once,
+            Self::Doce,
+            Self::Trece,
+            Self::Catorce,
+            Self::Quince,
+            Self::Dieciséis,
+            Self::Diecisiete,
+            Self::Dieciocho,
+            Self::Diecinueve,
+            Self::Veinte,
+            Self::Veintiún,
+            Self::Veintiuna,
+            Self::Veintiuno,
+            Self::Veintidós,
+            Self::Veintitrés,
+            Self::Veinticuatro,
+            Self::Veinticinco,
+            Self::Veintiséis,
+            Self::Veintisiete,
+            Self::Veintiocho,
+            Self::Veintinueve,
             Self::Calle,
             Self::Calles,
             Self::Hasta,
+            Self::Carrera,
+            Self::Avenida,
+            Self::Autopista,
+            Self::Norte,
+            Self::Sur,
+            Self::Este,
+            Self::Oeste,
+            Self::Hacia,
+            Self::Ve,
+            Self::Del,
+            Self::Casa,
+            Self::Tienda,
+            Self::Hospital,
+            Self::Parque,
+            Self::Supermercado,
+            Self::Hotel,
+            Self::Enfrente,
+            Self::Da,
+            Self::Media,
+            Self::Vuelta,
+            Self::Regresa,
+            Self::Por,
+            Self::Donde,
+            Self::Viniste,
+            Self::Vino,
+            Self::Y,
+            Self::Luego,
+            Self::Después,
         ]
     }
 }
@@ -215,6 +754,7 @@ impl Lexeme {
 impl Display for Lexeme {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
+            Lexeme::Numeral(n) => return write!(f, "{n}"),
             Lexeme::En => "en",
             Lexeme::A => "a",
             Lexeme::De => "de",
@@ -235,21 +775,118 @@ impl Display for Lexeme {
             Lexeme::Toma => "toma",
             Lexeme::Gira => "gira",
             Lexeme::Continúa => "continúa",
+            Lexeme::Sigue => "sigue",
+            Lexeme::Dobla => "dobla",
+            Lexeme::Cruza => "cruza",
+            Lexeme::Camina => "camina",
+            Lexeme::Pasa => "pasa",
+            Lexeme::Tome => "tome",
+            Lexeme::Gire => "gire",
+            Lexeme::Continúe => "continúe",
+            Lexeme::Siga => "siga",
+            Lexeme::Doble => "doble",
+            Lexeme::Tomá => "tomá",
+            Lexeme::Girá => "girá",
+            Lexeme::Seguí => "seguí",
+            Lexeme::Doblá => "doblá",
+            Lexeme::Cruce => "cruce",
+            Lexeme::Camine => "camine",
+            Lexeme::Pase => "pase",
+            Lexeme::Dé => "dé",
+            Lexeme::Regrese => "regrese",
+            Lexeme::Vaya => "vaya",
+            Lexeme::Cruzá => "cruzá",
+            Lexeme::Caminá => "caminá",
+            Lexeme::Pasá => "pasá",
+            Lexeme::Regresá => "regresá",
+            Lexeme::Andá => "andá",
             Lexeme::Primero => "primero",
             Lexeme::Segundo => "segundo",
             Lexeme::Tercera => "tercera",
             Lexeme::Tercero => "tercero",
             Lexeme::Cuarta => "cuarta",
             Lexeme::Cuarto => "cuarto",
+            Lexeme::Quinta => "quinta",
+            Lexeme::Quinto => "quinto",
+            Lexeme::Sexta => "sexta",
+            Lexeme::Sexto => "sexto",
+            Lexeme::Séptima => "séptima",
+            Lexeme::Séptimo => "séptimo",
+            Lexeme::Octava => "octava",
+            Lexeme::Octavo => "octavo",
+            Lexeme::Novena => "novena",
+            Lexeme::Noveno => "noveno",
+            Lexeme::Décima => "décima",
+            Lexeme::Décimo => "décimo",
+            Lexeme::Última => "última",
+            Lexeme::Último => "último",
+            Lexeme::Próxima => "próxima",
+            Lexeme::Próximo => "próximo",
+            Lexeme::Siguiente => "siguiente",
             Lexeme::Un => "un",
             Lexeme::Una => "una",
             Lexeme::Uno => "uno",
             Lexeme::Dos => "dos",
             Lexeme::Tres => "tres",
             Lexeme::Quatro => "quatro",
+            Lexeme::Cinco => "cinco",
+            Lexeme::Seis => "seis",
+            Lexeme::Siete => "siete",
+            Lexeme::Ocho => "ocho",
+            Lexeme::Nueve => "nueve",
+            Lexeme::Diez => "diez",
+            Lexeme::Once => "once",
+            Lexeme::Doce => "doce",
+            Lexeme::Trece => "trece",
+            Lexeme::Catorce => "catorce",
+            Lexeme::Quince => "quince",
+            Lexeme::Dieciséis => "dieciséis",
+            Lexeme::Diecisiete => "diecisiete",
+            Lexeme::Dieciocho => "dieciocho",
+            Lexeme::Diecinueve => "diecinueve",
+            Lexeme::Veinte => "veinte",
+            Lexeme::Veintiún => "veintiún",
+            Lexeme::Veintiuna => "veintiuna",
+            Lexeme::Veintiuno => "veintiuno",
+            Lexeme::Veintidós => "veintidós",
+            Lexeme::Veintitrés => "veintitrés",
+            Lexeme::Veinticuatro => "veinticuatro",
+            Lexeme::Veinticinco => "veinticinco",
+            Lexeme::Veintiséis => "veintiséis",
+            Lexeme::Veintisiete => "veintisiete",
+            Lexeme::Veintiocho => "veintiocho",
+            Lexeme::Veintinueve => "veintinueve",
             Lexeme::Calle => "calle",
             Lexeme::Calles => "calles",
             Lexeme::Hasta => "hasta",
+            Lexeme::Carrera => "carrera",
+            Lexeme::Avenida => "avenida",
+            Lexeme::Autopista => "autopista",
+            Lexeme::Norte => "norte",
+            Lexeme::Sur => "sur",
+            Lexeme::Este => "este",
+            Lexeme::Oeste => "oeste",
+            Lexeme::Hacia => "hacia",
+            Lexeme::Ve => "ve",
+            Lexeme::Del => "del",
+            Lexeme::Casa => "casa",
+            Lexeme::Tienda => "tienda",
+            Lexeme::Hospital => "hospital",
+            Lexeme::Parque => "parque",
+            Lexeme::Supermercado => "supermercado",
+            Lexeme::Hotel => "hotel",
+            Lexeme::Enfrente => "enfrente",
+            Lexeme::Da => "da",
+            Lexeme::Media => "media",
+            Lexeme::Vuelta => "vuelta",
+            Lexeme::Regresa => "regresa",
+            Lexeme::Por => "por",
+            Lexeme::Donde => "donde",
+            Lexeme::Viniste => "viniste",
+            Lexeme::Vino => "vino",
+            Lexeme::Y => "y",
+            Lexeme::Luego => "luego",
+            Lexeme::Después => "después",
         };
         write!(f, "{s}")
     }
@@ -263,10 +900,109 @@ impl FromStr for Lexeme {
     }
 }
 
+/// A number written with digits and optionally followed by a single letter.
+///
+/// Examples:
+/// * "87"
+/// * "19b"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Numeral {
+    value: u32,
+    suffix: Option<char>,
+}
+
+impl Numeral {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn suffix(&self) -> Option<char> {
+        self.suffix
+    }
+
+    fn from_lowercase(s: &str) -> Option<Numeral> {
+        let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, suffix) = s.split_at(digits_end);
+        let value = digits.parse().ok()?;
+
+        let mut suffix_chars = suffix.chars();
+        match (suffix_chars.next(), suffix_chars.next()) {
+            (None, _) => Some(Self {
+                value,
+                suffix: None,
+            }),
+            (Some(c), None) if c.is_alphabetic() => Some(Self {
+                value,
+                suffix: Some(c),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Numeral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)?;
+        if let Some(c) = self.suffix {
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Error)]
 #[error(r#""{0}" is not a recognized lexeme"#)]
 pub struct FromStrError(pub String);
 
 #[derive(Debug, Error)]
 #[error("The words are are not recognized.")]
-pub struct LexError(pub Vec<String>);
+pub struct LexError(pub Vec<WordError>);
+
+/// A word that could not be read as a lexeme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordError {
+    Unknown(UnknownWord),
+
+    /// The word spells the lexeme, but is missing its accents or has the
+    /// wrong ones.
+    Accents {
+        word: String,
+        lexeme: Lexeme,
+    },
+}
+
+impl Display for WordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordError::Unknown(unknown) => write!(f, "{unknown}"),
+            WordError::Accents { word, lexeme } => {
+                write!(f, "\"{word}\" (write \"{lexeme}\")")
+            }
+        }
+    }
+}
+
+/// A word that is not a recognized lexeme, along with the lexeme it was most
+/// likely meant to be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownWord {
+    pub word: String,
+    pub suggestion: Option<Lexeme>,
+}
+
+impl UnknownWord {
+    fn new(FromStrError(word): FromStrError, word_bank: &[Lexeme]) -> Self {
+        let suggestion = spelling::suggest(&word, word_bank);
+        Self { word, suggestion }
+    }
+}
+
+impl Display for UnknownWord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\"", self.word)?;
+        if let Some(l) = self.suggestion {
+            write!(f, " (did you mean \"{l}\"?)")?;
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,15 @@
+mod diagnostic;
 mod lex;
+mod register;
+mod spelling;
 pub mod syntax;
+mod token;
 
-pub use lex::{LexError, Lexeme};
-pub use syntax::{Sentence, SentenceParseError};
+pub use diagnostic::Diagnostic;
+pub use lex::{LexError, Lexeme, WordError};
+pub use register::Register;
+pub use syntax::{Directions, DirectionsParseError, Sentence};
+pub use token::Accents;
 
 // String -> Tokens -> Lexems -> Syntax Tree (Sentence) -> [Rel Command] -|
 //                                                                   Map  +--> [Abs Command]
@@ -0,0 +1,141 @@
+//! This module defines the forms of address that an imperative verb can take.
+
+use serde::Deserialize;
+use std::fmt::Display;
+
+use super::Lexeme;
+
+/// The form of address used to give directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum Register {
+    /// Informal, as in "toma la primera calle".
+    #[serde(rename = "tú", alias = "tu")]
+    Tú,
+
+    /// Formal, as in "tome la primera calle".
+    #[serde(rename = "usted")]
+    Usted,
+
+    /// Informal voseo, as in "tomá la primera calle".
+    #[serde(rename = "vos")]
+    Vos,
+}
+
+impl Register {
+    /// Returns the registers in which the lexeme can be used. Lexemes that are
+    /// not verbs addressed to the player can be used in any register, so none
+    /// are returned.
+    pub fn of(lexeme: Lexeme) -> &'static [Register] {
+        match lexeme {
+            Lexeme::Toma
+            | Lexeme::Gira
+            | Lexeme::Dobla
+            | Lexeme::Continúa
+            | Lexeme::Sigue
+            | Lexeme::Cruza
+            | Lexeme::Camina
+            | Lexeme::Pasa
+            | Lexeme::Regresa
+            | Lexeme::Ve => &[Register::Tú],
+            // "da" is both the tú and the vos form of "dar", and "viniste" is
+            // the preterite of both.
+            Lexeme::Da | Lexeme::Viniste => &[Register::Tú, Register::Vos],
+            Lexeme::Tome
+            | Lexeme::Gire
+            | Lexeme::Continúe
+            | Lexeme::Siga
+            | Lexeme::Doble
+            | Lexeme::Cruce
+            | Lexeme::Camine
+            | Lexeme::Pase
+            | Lexeme::Dé
+            | Lexeme::Regrese
+            | Lexeme::Vaya
+            | Lexeme::Vino => &[Register::Usted],
+            Lexeme::Tomá
+            | Lexeme::Girá
+            | Lexeme::Seguí
+            | Lexeme::Doblá
+            | Lexeme::Cruzá
+            | Lexeme::Caminá
+            | Lexeme::Pasá
+            | Lexeme::Regresá
+            | Lexeme::Andá => &[Register::Vos],
+            _ => &[],
+        }
+    }
+
+    /// Returns whether the lexeme can be used in this register.
+    pub fn allows(self, lexeme: Lexeme) -> bool {
+        let registers = Self::of(lexeme);
+        registers.is_empty() || registers.contains(&self)
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Register::Tú => write!(f, "tú"),
+            Register::Usted => write!(f, "usted"),
+            Register::Vos => write!(f, "vos"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Register;
+    use crate::lang::{Directions, Lexeme};
+
+    #[test]
+    fn registers_share_sentences() {
+        let parse = |line| Directions::parse(&Lexeme::parse_line(line).unwrap()).unwrap();
+        let tú =
+            parse("toma la primera calle a la derecha y luego continúa todo derecho dos quadras");
+        assert_eq!(
+            parse("tome la primera calle a la derecha y luego siga todo derecho dos quadras"),
+            tú
+        );
+        assert_eq!(
+            parse("tomá la primera calle a la derecha y luego seguí todo derecho dos quadras"),
+            tú
+        );
+
+        assert!(Register::Usted.allows(Lexeme::Doble));
+        assert!(!Register::Usted.allows(Lexeme::Doblá));
+        assert!(Register::Vos.allows(Lexeme::Da));
+        assert!(Register::Tú.allows(Lexeme::Calle));
+    }
+    /// Directions that use each of the verbs with an usted and a vos form
+    /// besides those in `registers_share_sentences`.
+    const TÚ: &str = "da la vuelta y luego regresa por donde viniste y luego ve al norte \
+        y luego cruza una calle y luego camina dos quadras y luego pasa tres calles";
+
+    fn parse(line: &str) -> Directions {
+        Directions::parse(&Lexeme::parse_line(line).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn usted_forms() {
+        let line = "dé la vuelta y luego regrese por donde vino y luego vaya al norte \
+            y luego cruce una calle y luego camine dos quadras y luego pase tres calles";
+        assert_eq!(parse(line), parse(TÚ));
+
+        let lexemes = Lexeme::parse_line(line).unwrap();
+        assert!(lexemes.iter().all(|l| Register::Usted.allows(*l)));
+        assert!(!lexemes.iter().all(|l| Register::Tú.allows(*l)));
+        assert!(!Register::Usted.allows(Lexeme::Viniste));
+    }
+
+    #[test]
+    fn vos_forms() {
+        let line = "da la vuelta y luego regresá por donde viniste y luego andá al norte \
+            y luego cruzá una calle y luego caminá dos quadras y luego pasá tres calles";
+        assert_eq!(parse(line), parse(TÚ));
+
+        let lexemes = Lexeme::parse_line(line).unwrap();
+        assert!(lexemes.iter().all(|l| Register::Vos.allows(*l)));
+        assert!(!lexemes.iter().all(|l| Register::Tú.allows(*l)));
+        assert!(!Register::Vos.allows(Lexeme::Vaya));
+    }
+}
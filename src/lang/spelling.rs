@@ -0,0 +1,74 @@
+//! This module suggests known lexemes for misspelled words.
+
+use super::Lexeme;
+
+/// Returns the lexeme from `candidates` that the misspelled word was most likely
+/// meant to be, if any is close enough.
+///
+/// Words are compared with their accents removed first, so a word that is only
+/// missing an accent, such as "continua", always finds its lexeme.
+pub fn suggest(word: &str, candidates: &[Lexeme]) -> Option<Lexeme> {
+    let word = word.to_lowercase();
+    let folded = fold_accents(&word);
+    let max_distance = (folded.chars().count() / 3).clamp(1, 2);
+
+    candidates
+        .iter()
+        .map(|&l| {
+            let lexeme_str = l.to_string();
+            let folded_distance = edit_distance(&folded, &fold_accents(&lexeme_str));
+            (l, folded_distance, edit_distance(&word, &lexeme_str))
+        })
+        .filter(|(_, folded_distance, _)| *folded_distance <= max_distance)
+        .min_by_key(|(_, folded_distance, distance)| (*folded_distance, *distance))
+        .map(|(l, _, _)| l)
+}
+
+/// Replaces each accented vowel with its unaccented form.
+pub fn fold_accents(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            'á' => 'a',
+            'é' => 'e',
+            'í' => 'i',
+            'ó' => 'o',
+            'ú' | 'ü' => 'u',
+            _ => c,
+        })
+        .collect()
+}
+
+/// The Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr.push(substitution.min(prev[j + 1] + 1).min(curr[j] + 1));
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::suggest;
+    use crate::lang::Lexeme;
+
+    #[test]
+    fn suggest_misspellings() {
+        let all = Lexeme::all();
+        assert_eq!(suggest("continua", &all), Some(Lexeme::Continúa));
+        assert_eq!(suggest("Despues", &all), Some(Lexeme::Después));
+        assert_eq!(suggest("cuadra", &all), Some(Lexeme::Quadra));
+        assert_eq!(suggest("xyzzy", &all), None);
+
+        // Only the candidates are suggested, so a word outside of them is
+        // never offered.
+        assert_eq!(suggest("cuadra", &[Lexeme::Quadras]), Some(Lexeme::Quadras));
+        assert_eq!(suggest("cuadra", &[Lexeme::Gira]), None);
+    }
+}
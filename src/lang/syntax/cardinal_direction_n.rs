@@ -0,0 +1,64 @@
+use crate::lang::Lexeme;
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardinalDirectionNoun {
+    Norte,
+    Sur,
+    Este,
+    Oeste,
+}
+
+impl CardinalDirectionNoun {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (l, rest) = lexemes.split_first().ok_or(ParseError::Empty)?;
+        let noun = match l {
+            Lexeme::Norte => Ok(Self::Norte),
+            Lexeme::Sur => Ok(Self::Sur),
+            Lexeme::Este => Ok(Self::Este),
+            Lexeme::Oeste => Ok(Self::Oeste),
+            _ => Err(ParseError::Unrecognized(*l)),
+        }?;
+        Ok((noun, rest))
+    }
+}
+
+impl CardinalDirectionNoun {
+    pub fn lexeme(&self) -> Lexeme {
+        match self {
+            CardinalDirectionNoun::Norte => Lexeme::Norte,
+            CardinalDirectionNoun::Sur => Lexeme::Sur,
+            CardinalDirectionNoun::Este => Lexeme::Este,
+            CardinalDirectionNoun::Oeste => Lexeme::Oeste,
+        }
+    }
+}
+
+impl Display for CardinalDirectionNoun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lexeme())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("There are no words to parse as a cardinal direction.")]
+    Empty,
+
+    #[error(
+        "\"{0}\" is not a cardinal direction. It must be \"norte\", \"sur\", \"este\" or \"oeste\"."
+    )]
+    Unrecognized(Lexeme),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::Empty => Span::new(0, 0),
+            ParseError::Unrecognized(_) => Span::at(0),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::lang::Lexeme;
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::{parse, CardinalDirectionNoun, CardinalDirectionNounParseError, Span};
+
+/// Prepositional phrase describing a cardinal direction.
+///
+/// Examples:
+/// * "al norte"
+/// * "hacia el este"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardinalPrepPhrase(pub CardinalDirectionNoun);
+
+impl CardinalPrepPhrase {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let ((), rest) = parse::consume_lexeme(lexemes, Lexeme::Al)
+            .or_else(|| {
+                parse::consume_lexeme(lexemes, Lexeme::Hacia)
+                    .and_then(|((), rest)| parse::consume_lexeme(rest, Lexeme::El))
+            })
+            .ok_or(ParseError::MissingAlOrHaciaEl)?;
+        let (n, rest) = CardinalDirectionNoun::try_parse(rest)
+            .map_err(|e| ParseError::MissingDirection(parse::offset(lexemes, rest), e))?;
+        Ok((CardinalPrepPhrase(n), rest))
+    }
+}
+
+impl Display for CardinalPrepPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", Lexeme::Al, self.0)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error(r#"The direction must be preceded by either "al" or "hacia el"."#)]
+    MissingAlOrHaciaEl,
+
+    #[error(r#"The prepositional phrase must contain a cardinal direction: {1}"#)]
+    MissingDirection(usize, #[source] CardinalDirectionNounParseError),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingAlOrHaciaEl => Span::at(0),
+            ParseError::MissingDirection(offset, e) => e.span().offset(*offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CardinalPrepPhrase;
+    use crate::lang::{syntax::CardinalDirectionNoun, Lexeme, Sentence};
+
+    #[test]
+    fn parse_cardinal_sentences() {
+        let parse = |line| {
+            Sentence::try_parse(&Lexeme::parse_line(line).unwrap())
+                .map(|(s, rest)| (s, rest.is_empty()))
+        };
+        assert_eq!(
+            parse("ve al norte").unwrap(),
+            (
+                Sentence::VeCardinalPp(CardinalPrepPhrase(CardinalDirectionNoun::Norte)),
+                true
+            )
+        );
+        assert_eq!(
+            parse("gira hacia el este").unwrap(),
+            (
+                Sentence::GiraCardinalPp(CardinalPrepPhrase(CardinalDirectionNoun::Este)),
+                true
+            )
+        );
+        assert!(parse("ve hacia norte").is_err());
+    }
+}
@@ -17,10 +17,18 @@ impl DefiniteArticle {
         }
     }
 
+    pub fn masc_sing() -> Self {
+        Self {
+            gender: Gender::Masculine,
+            quantity: Quantity::Singular,
+        }
+    }
+
     pub fn try_parse(lexemes: &[Lexeme]) -> Option<(Self, &[Lexeme])> {
         let (l, rest) = lexemes.split_first()?;
         let article = match l {
             Lexeme::La => Some(Self::fem_sing()),
+            Lexeme::El => Some(Self::masc_sing()),
             _ => None,
         }?;
         Some((article, rest))
@@ -0,0 +1,238 @@
+use crate::lang::Lexeme;
+use iter_tools::Itertools;
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::{parse, Sentence, SentenceParseError, Span};
+
+/// A sequence of one or more sentences chained together by conjunctions.
+///
+/// Examples:
+/// * "Gira a la derecha."
+/// * "Continúa todo derecho dos quadras y luego gira a la derecha."
+/// * "Toma la primera calle a la izquierda después gira a la derecha."
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directions(pub Vec<Sentence>);
+
+impl Directions {
+    pub fn parse(lexemes: &[Lexeme]) -> Result<Self, ParseError> {
+        let (first, mut rest) =
+            Sentence::try_parse(lexemes).map_err(|e| ParseError::Sentence(0, 0, e))?;
+        let mut sentences = vec![first];
+
+        while !rest.is_empty() {
+            let ((), after_conj) = try_parse_conjunction(rest).ok_or_else(|| {
+                ParseError::LexemesAfterSentence(Span::at(parse::offset(lexemes, rest)))
+            })?;
+            let (s, after_s) = Sentence::try_parse(after_conj).map_err(|e| {
+                ParseError::Sentence(sentences.len(), parse::offset(lexemes, after_conj), e)
+            })?;
+            sentences.push(s);
+            rest = after_s;
+        }
+
+        Ok(Self(sentences))
+    }
+}
+
+impl Display for Directions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let conjunction = format!(" {} {} ", Lexeme::Y, Lexeme::Luego);
+        write!(f, "{}", self.0.iter().join(&conjunction))
+    }
+}
+
+/// Consumes a conjunction that joins two sentences.
+///
+/// Examples:
+/// * "y"
+/// * "luego"
+/// * "después"
+/// * "y luego"
+/// * "y después"
+fn try_parse_conjunction(lexemes: &[Lexeme]) -> Option<((), &[Lexeme])> {
+    let then = |ls| {
+        parse::or(
+            ls,
+            |ls| parse::consume_lexeme(ls, Lexeme::Luego),
+            |ls| parse::consume_lexeme(ls, Lexeme::Después),
+        )
+    };
+
+    match parse::consume_lexeme(lexemes, Lexeme::Y) {
+        Some(((), rest)) => then(rest).or(Some(((), rest))),
+        None => then(lexemes),
+    }
+}
+
+/// An error that occurs trying to parse directions.
+///
+/// `Sentence` stores the index of the failing sentence and the index of the
+/// lexeme at which that sentence starts.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Sentence {}: {2}", .0 + 1)]
+    Sentence(usize, usize, #[source] SentenceParseError),
+
+    #[error(r#"Sentences must be joined by "y", "luego" or "después"."#)]
+    LexemesAfterSentence(Span),
+}
+
+impl ParseError {
+    /// The lexemes that caused the error.
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::Sentence(_, offset, e) => e.span().offset(*offset),
+            ParseError::LexemesAfterSentence(span) => *span,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Directions;
+    use crate::lang::{
+        syntax::{
+            gender::Gender, landmark_np::LandmarkNoun, CardinalDirectionNoun, CardinalPrepPhrase,
+            DistanceNounPhrase, EndOfStreetNounPhrase, ForwardNounPhrase, LandmarkNounPhrase,
+            LeftRightTurnPrepPhrase, Ordinality, Sentence, StreetName, StreetNounPhrase,
+            TurnDirectionNoun, TurnDirectionNounPhrase, TurnableNounPhrase, UTurnNounPhrase,
+        },
+        Lexeme,
+    };
+    use proptest::{option, prelude::*};
+    use std::num::NonZeroUsize;
+
+    #[test]
+    fn parse_chained_sentences() {
+        let lexemes = Lexeme::parse_line(
+            "Continúa todo derecho dos quadras y luego gira a la derecha después gira a mano izquierda",
+        )
+        .unwrap();
+        let result = Directions::parse(&lexemes).unwrap();
+
+        let derecha = TurnDirectionNounPhrase(TurnDirectionNoun::Derecha);
+        let izquierda = TurnDirectionNounPhrase(TurnDirectionNoun::Izquierda);
+        let expected = Directions(vec![
+            Sentence::ContinúaNpNp(
+                ForwardNounPhrase(),
+                DistanceNounPhrase::NQuadras(NonZeroUsize::new(2).unwrap()),
+            ),
+            Sentence::GiraPp(LeftRightTurnPrepPhrase(derecha)),
+            Sentence::GiraPp(LeftRightTurnPrepPhrase(izquierda)),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_requires_conjunction() {
+        let lexemes = Lexeme::parse_line("gira a la derecha gira a la izquierda").unwrap();
+        assert!(Directions::parse(&lexemes).is_err());
+    }
+
+    fn turn_pp() -> impl Strategy<Value = LeftRightTurnPrepPhrase> {
+        prop_oneof![
+            Just(TurnDirectionNoun::Izquierda),
+            Just(TurnDirectionNoun::Derecha)
+        ]
+        .prop_map(|n| LeftRightTurnPrepPhrase(TurnDirectionNounPhrase(n)))
+    }
+
+    fn cardinal_pp() -> impl Strategy<Value = CardinalPrepPhrase> {
+        prop_oneof![
+            Just(CardinalDirectionNoun::Norte),
+            Just(CardinalDirectionNoun::Sur),
+            Just(CardinalDirectionNoun::Este),
+            Just(CardinalDirectionNoun::Oeste),
+        ]
+        .prop_map(CardinalPrepPhrase)
+    }
+
+    fn landmark_np() -> impl Strategy<Value = LandmarkNounPhrase> {
+        prop_oneof![
+            Just(LandmarkNoun::Casa),
+            Just(LandmarkNoun::Tienda),
+            Just(LandmarkNoun::Hospital),
+            Just(LandmarkNoun::Parque),
+            Just(LandmarkNoun::Supermercado),
+            Just(LandmarkNoun::Hotel),
+        ]
+        .prop_map(LandmarkNounPhrase)
+    }
+
+    fn street_np() -> impl Strategy<Value = StreetNounPhrase> {
+        let gender = prop_oneof![Just(Gender::Feminine), Just(Gender::Masculine)];
+        let ord = prop_oneof![
+            (0..=20usize, gender.clone())
+                .prop_filter_map("only the first ten have words", |(n, g)| {
+                    Ordinality::new(n, g)
+                }),
+            gender.prop_map(Ordinality::last),
+        ];
+        let name = prop_oneof![
+            Just("Calle 87"),
+            Just("Carrera 19b"),
+            Just("Avenida Calle 85"),
+            Just("Autopista Norte"),
+        ]
+        .prop_map(|s| {
+            let lexemes = Lexeme::parse_line(s).unwrap();
+            StreetName::try_parse(&lexemes).unwrap().0
+        });
+        prop_oneof![
+            Just(StreetNounPhrase::LaCalle),
+            ord.prop_map(StreetNounPhrase::LaCalleOrd),
+            name.prop_map(StreetNounPhrase::Named),
+        ]
+    }
+
+    fn sentence() -> impl Strategy<Value = Sentence> {
+        let turnable_np = || street_np().prop_map(TurnableNounPhrase::Street);
+        let forward_np = Just(ForwardNounPhrase());
+        let distance_np = (0..=100usize)
+            .prop_filter_map("no blocks is not a distance", NonZeroUsize::new)
+            .prop_map(DistanceNounPhrase::NQuadras);
+        prop_oneof![
+            turn_pp().prop_map(Sentence::EstáTurnPp),
+            (turn_pp(), landmark_np()).prop_map(|(pp, np)| Sentence::EstáTurnPpEnfrenteNp(pp, np)),
+            (turnable_np(), turn_pp()).prop_map(|(np, pp)| Sentence::EstáEnNpPp(np, pp)),
+            turn_pp().prop_map(Sentence::GiraPp),
+            cardinal_pp().prop_map(Sentence::GiraCardinalPp),
+            (turn_pp(), landmark_np()).prop_map(|(pp, np)| Sentence::GiraPpDespuésNp(pp, np)),
+            turn_pp().prop_map(|pp| Sentence::GiraPpAlFinalNp(pp, EndOfStreetNounPhrase())),
+            (turnable_np(), turn_pp()).prop_map(|(np, pp)| Sentence::GiraNpPp(np, pp)),
+            (turnable_np(), turn_pp()).prop_map(|(np, pp)| Sentence::TomaNpPp(np, pp)),
+            (forward_np, distance_np.clone()).prop_map(|(f, d)| Sentence::ContinúaNpNp(f, d)),
+            (option::of(forward_np), street_np(), option::of(turn_pp()))
+                .prop_map(|(f, s, pp)| Sentence::ContinúaNpHastaNp(f, s, pp)),
+            (option::of(forward_np), landmark_np())
+                .prop_map(|(f, np)| Sentence::ContinúaNpHastaLandmarkNp(f, np)),
+            option::of(forward_np)
+                .prop_map(|f| Sentence::ContinúaNpHastaFinalNp(f, EndOfStreetNounPhrase())),
+            (option::of(forward_np), distance_np.clone())
+                .prop_map(|(f, d)| Sentence::CaminaNpNp(f, d)),
+            distance_np.clone().prop_map(Sentence::CruzaNp),
+            distance_np.clone().prop_map(Sentence::PasaNp),
+            prop_oneof![
+                Just(UTurnNounPhrase::LaVuelta),
+                Just(UTurnNounPhrase::MediaVuelta)
+            ]
+            .prop_map(Sentence::DaNp),
+            Just(Sentence::RegresaPorDondeViniste),
+            cardinal_pp().prop_map(Sentence::VeCardinalPp),
+            (option::of(forward_np), distance_np).prop_map(|(f, d)| Sentence::VeNpNp(f, d)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn render_then_parse(sentences in prop::collection::vec(sentence(), 1..4)) {
+            let directions = Directions(sentences);
+            let text = directions.to_string();
+            let lexemes = Lexeme::parse_line(&text).unwrap();
+            let parsed = Directions::parse(&lexemes);
+            prop_assert!(parsed.is_ok(), "{text:?} did not parse: {parsed:?}");
+            prop_assert_eq!(parsed.unwrap(), directions, "{}", text);
+        }
+    }
+}
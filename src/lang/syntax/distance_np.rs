@@ -1,75 +1,70 @@
+use std::fmt::Display;
+use std::num::NonZeroUsize;
 use thiserror::Error;
 
-use crate::lang::{
-    syntax::{number::Unx, quantity::HasQuantity},
-    Lexeme,
-};
+use crate::lang::{syntax::quantity::HasQuantity, Lexeme};
 
-use super::{distance_n::DistanceNoun, parse, Number};
+use super::{distance_n::DistanceNoun, parse, Number, Span};
 
 /// A noun phrase describing a distance.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DistanceNounPhrase {
     /// A specified number of blocks or streets.
-    NQuadras(usize),
+    NQuadras(NonZeroUsize),
 }
 
 impl DistanceNounPhrase {
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
         let (before, distance_n, after) =
             parse::split_at_parsable(lexemes, DistanceNoun::try_parse)
-                .ok_or(ParseError::MissingDistanceNoun)?;
+                .ok_or(ParseError::MissingDistanceNoun(Span::new(0, lexemes.len())))?;
 
         let (number, after_number) = Number::try_parse(before)
-            .map_err(|_| ParseError::NotImmediatelyPrecededNumberOrIndefiniteArticle)?;
+            .map_err(|e| ParseError::NotImmediatelyPrecededNumberOrIndefiniteArticle(e.span()))?;
+        let count = NonZeroUsize::new(number.value()).ok_or(
+            ParseError::NotImmediatelyPrecededNumberOrIndefiniteArticle(Span::at(0)),
+        )?;
 
         if !after_number.is_empty() {
-            return Err(ParseError::NotImmediatelyPrecededNumberOrIndefiniteArticle);
+            return Err(ParseError::NotImmediatelyPrecededNumberOrIndefiniteArticle(
+                Span::new(1, before.len()),
+            ));
         }
 
-        // TODO: Change this to match on (number, distance_n.gender,
-        // distance_n.quantity) if additional variables of DistanceNoun are
-        // added.
-        match number {
-            Number::Unx(Unx::Un) => {
-                if distance_n.is_sing() {
-                    Err(ParseError::GenderDisagreement(number, distance_n.lexeme()))
-                } else {
-                    Err(ParseError::GenderAndQuantityDisagreement(
-                        number,
-                        distance_n.lexeme(),
-                    ))
-                }
-            }
-            Number::Unx(Unx::Una) => {
-                if distance_n.is_sing() {
-                    Ok((DistanceNounPhrase::NQuadras(1), after))
-                } else {
-                    Err(ParseError::QuantityDisagreement(
-                        number,
-                        distance_n.lexeme(),
-                    ))
-                }
-            }
-            Number::Unx(Unx::Uno) => {
-                if distance_n.is_sing() {
-                    Err(ParseError::GenderDisagreement(number, distance_n.lexeme()))
-                } else {
-                    Err(ParseError::GenderAndQuantityDisagreement(
-                        number,
-                        distance_n.lexeme(),
-                    ))
-                }
+        // "quadra" and "calle" are both feminine, so only the feminine forms of
+        // one agree with them.
+        // TODO: Check the gender of the distance noun if masculine distance
+        // nouns are added.
+        let gender_agrees = number.agrees_with_fem();
+        let quantity_agrees = if number.value() == 1 {
+            distance_n.is_sing()
+        } else {
+            distance_n.is_pl()
+        };
+        match (gender_agrees, quantity_agrees) {
+            (true, true) => Ok((DistanceNounPhrase::NQuadras(count), after)),
+            (false, true) => Err(ParseError::GenderDisagreement(number, distance_n.lexeme())),
+            (true, false) => Err(ParseError::QuantityDisagreement(
+                number,
+                distance_n.lexeme(),
+            )),
+            (false, false) => Err(ParseError::GenderAndQuantityDisagreement(
+                number,
+                distance_n.lexeme(),
+            )),
+        }
+    }
+}
+
+impl Display for DistanceNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // "quadra" is feminine, so one block is "una quadra".
+        match self {
+            DistanceNounPhrase::NQuadras(n) if n.get() == 1 => {
+                write!(f, "{} {}", Number::una(), Lexeme::Quadra)
             }
-            Number::Mas(val) => {
-                if distance_n.is_pl() {
-                    Ok((DistanceNounPhrase::NQuadras(val), after))
-                } else {
-                    Err(ParseError::QuantityDisagreement(
-                        number,
-                        distance_n.lexeme(),
-                    ))
-                }
+            DistanceNounPhrase::NQuadras(n) => {
+                write!(f, "{} {}", Number::fem(*n), Lexeme::Quadras)
             }
         }
     }
@@ -80,17 +75,68 @@ pub enum ParseError {
     #[error(
         "The distance noun must be immediately preceded by a number or an indefinite article."
     )]
-    NotImmediatelyPrecededNumberOrIndefiniteArticle,
+    NotImmediatelyPrecededNumberOrIndefiniteArticle(Span),
 
-    #[error(r#"The noun "{1}" and "{0}" must agree in gender."#)]
+    #[error(r#""{0}" is masculine, but "{1}" is feminine. They must agree in gender."#)]
     GenderDisagreement(Number, Lexeme),
 
-    #[error(r#"The noun "{1}" and "{0}" must agree in number."#)]
+    #[error(r#""{0}" and "{1}" must agree in number. Both must be singular or both plural."#)]
     QuantityDisagreement(Number, Lexeme),
 
     #[error(r#"The noun "{1}" and "{0}" must agree in gender and number."#)]
     GenderAndQuantityDisagreement(Number, Lexeme),
 
     #[error("The phrase must contain a distance noun.")]
-    MissingDistanceNoun,
+    MissingDistanceNoun(Span),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::NotImmediatelyPrecededNumberOrIndefiniteArticle(span) => *span,
+            // The number and the distance noun are the first two lexemes.
+            ParseError::GenderDisagreement(_, _)
+            | ParseError::QuantityDisagreement(_, _)
+            | ParseError::GenderAndQuantityDisagreement(_, _) => Span::new(0, 2),
+            ParseError::MissingDistanceNoun(span) => *span,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DistanceNounPhrase, ParseError};
+    use crate::lang::Lexeme;
+    use std::num::NonZeroUsize;
+
+    fn parse(line: &str) -> Result<DistanceNounPhrase, ParseError> {
+        let lexemes = Lexeme::parse_line(line).unwrap();
+        DistanceNounPhrase::try_parse(&lexemes).map(|(np, _)| np)
+    }
+
+    fn quadras(n: usize) -> DistanceNounPhrase {
+        DistanceNounPhrase::NQuadras(NonZeroUsize::new(n).unwrap())
+    }
+
+    #[test]
+    fn parse_large_numbers() {
+        assert_eq!(parse("cinco quadras").unwrap(), quadras(5));
+        assert_eq!(parse("veintiuna calles").unwrap(), quadras(21));
+        assert_eq!(parse("7 quadras").unwrap(), quadras(7));
+        assert_eq!(parse("1 quadra").unwrap(), quadras(1));
+        assert!(parse("0 quadras").is_err());
+
+        assert!(matches!(
+            parse("veintiún quadras"),
+            Err(ParseError::GenderDisagreement(_, _))
+        ));
+        assert!(matches!(
+            parse("veintiuna quadra"),
+            Err(ParseError::QuantityDisagreement(_, _))
+        ));
+        assert!(matches!(
+            parse("7 quadra"),
+            Err(ParseError::QuantityDisagreement(_, _))
+        ));
+    }
 }
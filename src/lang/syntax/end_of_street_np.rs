@@ -0,0 +1,80 @@
+use crate::lang::{syntax::parse, Lexeme};
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::Span;
+
+/// A noun phrase describing the end of the street the player is on.
+///
+/// Examples:
+/// * "el final de la calle"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndOfStreetNounPhrase();
+
+impl EndOfStreetNounPhrase {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let ((), rest) =
+            parse::consume_lexeme(lexemes, Lexeme::El).ok_or(ParseError::MissingArticle)?;
+        Self::try_parse_final(rest)
+    }
+
+    /// Parses the noun phrase when its article has been contracted with the
+    /// preposition "a".
+    ///
+    /// Examples:
+    /// * "al final de la calle"
+    pub fn try_parse_al(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let ((), rest) = parse::consume_lexeme(lexemes, Lexeme::Al).ok_or(ParseError::MissingAl)?;
+        Self::try_parse_final(rest)
+    }
+
+    fn try_parse_final(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let ((), rest) =
+            parse::consume_lexeme(lexemes, Lexeme::Final).ok_or(ParseError::MissingFinal)?;
+        let ((), rest) = parse::consume_lexeme(rest, Lexeme::De)
+            .and_then(|((), rest)| parse::consume_lexeme(rest, Lexeme::La))
+            .and_then(|((), rest)| parse::consume_lexeme(rest, Lexeme::Calle))
+            .ok_or(ParseError::MissingDeLaCalle)?;
+        Ok((Self(), rest))
+    }
+}
+
+impl Display for EndOfStreetNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            Lexeme::El,
+            Lexeme::Final,
+            Lexeme::De,
+            Lexeme::La,
+            Lexeme::Calle
+        )
+    }
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum ParseError {
+    #[error("\"final\" must be preceded by the article \"el\".")]
+    MissingArticle,
+
+    #[error("\"final\" must be preceded by \"al\".")]
+    MissingAl,
+
+    #[error("The phrase must contain \"final\".")]
+    MissingFinal,
+
+    #[error("\"final\" must be followed by \"de la calle\".")]
+    MissingDeLaCalle,
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingArticle | ParseError::MissingAl => Span::at(0),
+            ParseError::MissingFinal => Span::at(1),
+            ParseError::MissingDeLaCalle => Span::new(2, 5),
+        }
+    }
+}
@@ -1,4 +1,8 @@
 use crate::lang::Lexeme;
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::Span;
 
 /// A noun phrase describing the forward direction.
 ///
@@ -10,19 +14,33 @@ pub struct ForwardNounPhrase();
 
 impl ForwardNounPhrase {
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
-        let (first, rest) = lexemes.split_first().ok_or(ParseError())?;
+        let (first, rest) = lexemes.split_first().ok_or(ParseError(Span::new(0, 0)))?;
         match *first {
             Lexeme::Derecho => Ok((ForwardNounPhrase(), rest)),
             Lexeme::Todo => {
-                let (second, rest) = rest.split_first().ok_or(ParseError())?;
+                let (second, rest) = rest.split_first().ok_or(ParseError(Span::new(1, 1)))?;
                 match *second {
                     Lexeme::Derecho => Ok((ForwardNounPhrase(), rest)),
-                    _ => Err(ParseError()),
+                    _ => Err(ParseError(Span::at(1))),
                 }
             }
-            _ => Err(ParseError()),
+            _ => Err(ParseError(Span::at(0))),
         }
     }
 }
 
-pub struct ParseError();
+impl Display for ForwardNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", Lexeme::Todo, Lexeme::Derecho)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error(r#"Expected "derecho" or "todo derecho"."#)]
+pub struct ParseError(Span);
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        self.0
+    }
+}
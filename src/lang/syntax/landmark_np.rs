@@ -0,0 +1,191 @@
+use crate::lang::{syntax::parse, Lexeme};
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::{
+    gender::{Gender, HasGender},
+    DefiniteArticle, Span,
+};
+
+/// A noun naming a building that can be used as a landmark.
+///
+/// The vocabulary is closed, so a landmark noun phrase is always one of these
+/// nouns with its article, such as "el hospital".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LandmarkNoun {
+    Casa,
+    Tienda,
+    Hospital,
+    Parque,
+    Supermercado,
+    Hotel,
+}
+
+impl LandmarkNoun {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (l, rest) = lexemes
+            .split_first()
+            .ok_or(ParseError::MissingLandmark(Span::new(0, 0)))?;
+        let noun = match l {
+            Lexeme::Casa => Ok(Self::Casa),
+            Lexeme::Tienda => Ok(Self::Tienda),
+            Lexeme::Hospital => Ok(Self::Hospital),
+            Lexeme::Parque => Ok(Self::Parque),
+            Lexeme::Supermercado => Ok(Self::Supermercado),
+            Lexeme::Hotel => Ok(Self::Hotel),
+            _ => Err(ParseError::MissingLandmark(Span::at(0))),
+        }?;
+        Ok((noun, rest))
+    }
+
+    pub fn lexeme(&self) -> Lexeme {
+        match self {
+            LandmarkNoun::Casa => Lexeme::Casa,
+            LandmarkNoun::Tienda => Lexeme::Tienda,
+            LandmarkNoun::Hospital => Lexeme::Hospital,
+            LandmarkNoun::Parque => Lexeme::Parque,
+            LandmarkNoun::Supermercado => Lexeme::Supermercado,
+            LandmarkNoun::Hotel => Lexeme::Hotel,
+        }
+    }
+}
+
+impl HasGender for LandmarkNoun {
+    fn gender(&self) -> Gender {
+        match self {
+            LandmarkNoun::Casa | LandmarkNoun::Tienda => Gender::Feminine,
+            LandmarkNoun::Hospital
+            | LandmarkNoun::Parque
+            | LandmarkNoun::Supermercado
+            | LandmarkNoun::Hotel => Gender::Masculine,
+        }
+    }
+}
+
+/// A noun phrase describing a landmark.
+///
+/// Examples:
+/// * "el parque"
+/// * "la casa"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LandmarkNounPhrase(pub LandmarkNoun);
+
+impl LandmarkNounPhrase {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (article, rest) =
+            DefiniteArticle::try_parse(lexemes).ok_or(ParseError::MissingArticle(Span::at(0)))?;
+        let (noun, rest) = LandmarkNoun::try_parse(rest).map_err(|e| e.offset(1))?;
+
+        if article.gender() != noun.gender() {
+            return Err(ParseError::ArticleGender(Span::new(0, 2), noun.lexeme()));
+        }
+
+        Ok((Self(noun), rest))
+    }
+
+    /// Reads a building's name as a landmark noun phrase, or returns `None` if
+    /// the name is not one.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let lexemes = Lexeme::parse_line(name).ok()?;
+        match Self::try_parse(&lexemes) {
+            Ok((np, [])) => Some(np),
+            _ => None,
+        }
+    }
+
+    /// Parses a landmark noun phrase that follows the preposition "de".
+    ///
+    /// Examples:
+    /// * "de la casa"
+    /// * "del parque"
+    pub fn try_parse_de(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        if let Some(((), rest)) = parse::consume_lexeme(lexemes, Lexeme::Del) {
+            let (noun, rest) = LandmarkNoun::try_parse(rest).map_err(|e| e.offset(1))?;
+            if !noun.is_masc() {
+                return Err(ParseError::DelGender(Span::new(0, 2), noun.lexeme()));
+            }
+            Ok((Self(noun), rest))
+        } else {
+            let ((), rest) = parse::consume_lexeme(lexemes, Lexeme::De)
+                .ok_or(ParseError::MissingDe(Span::at(0)))?;
+            Self::try_parse(rest).map_err(|e| e.offset(1))
+        }
+    }
+}
+
+impl Display for LandmarkNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let article = if self.0.is_fem() {
+            Lexeme::La
+        } else {
+            Lexeme::El
+        };
+        write!(f, "{} {}", article, self.0.lexeme())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("The landmark must be preceded by the article \"el\" or \"la\".")]
+    MissingArticle(Span),
+
+    #[error("The phrase must contain a landmark such as \"parque\" or \"casa\".")]
+    MissingLandmark(Span),
+
+    #[error("The article must agree in gender with \"{1}\".")]
+    ArticleGender(Span, Lexeme),
+
+    #[error("The landmark must be preceded by \"de\" or \"del\".")]
+    MissingDe(Span),
+
+    #[error("\"del\" can only precede a masculine noun. Use \"de la {1}\".")]
+    DelGender(Span, Lexeme),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingArticle(span)
+            | ParseError::MissingLandmark(span)
+            | ParseError::ArticleGender(span, _)
+            | ParseError::MissingDe(span)
+            | ParseError::DelGender(span, _) => *span,
+        }
+    }
+
+    fn offset(self, n: usize) -> Self {
+        match self {
+            ParseError::MissingArticle(span) => ParseError::MissingArticle(span.offset(n)),
+            ParseError::MissingLandmark(span) => ParseError::MissingLandmark(span.offset(n)),
+            ParseError::ArticleGender(span, l) => ParseError::ArticleGender(span.offset(n), l),
+            ParseError::MissingDe(span) => ParseError::MissingDe(span.offset(n)),
+            ParseError::DelGender(span, l) => ParseError::DelGender(span.offset(n), l),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LandmarkNoun, LandmarkNounPhrase};
+    use crate::lang::Lexeme;
+
+    #[test]
+    fn parse_de() {
+        let lexemes = Lexeme::parse_line("del parque").unwrap();
+        let (np, _) = LandmarkNounPhrase::try_parse_de(&lexemes).unwrap();
+        assert_eq!(np, LandmarkNounPhrase(LandmarkNoun::Parque));
+
+        let lexemes = Lexeme::parse_line("del casa").unwrap();
+        assert!(LandmarkNounPhrase::try_parse_de(&lexemes).is_err());
+
+        let lexemes = Lexeme::parse_line("de el casa").unwrap();
+        assert!(LandmarkNounPhrase::try_parse_de(&lexemes).is_err());
+
+        assert_eq!(
+            LandmarkNounPhrase::from_name("El Parque"),
+            Some(LandmarkNounPhrase(LandmarkNoun::Parque))
+        );
+        assert_eq!(LandmarkNounPhrase::from_name("el banco"), None);
+        assert_eq!(LandmarkNounPhrase::from_name("el parque central"), None);
+    }
+}
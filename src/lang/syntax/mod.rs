@@ -1,32 +1,51 @@
 //! This module defines the grammar of the language used in the game to describe
 //! directions.
 
+mod cardinal_direction_n;
+mod cardinal_pp;
 mod def_article;
+mod directions;
 mod distance_n;
 mod distance_np;
+mod end_of_street_np;
 mod forward_np;
 mod gender;
+mod landmark_np;
 mod number;
 mod ordinality;
 mod parse;
 mod quantity;
 mod sentence;
+mod span;
+mod street_name;
 mod street_np;
 mod turn_direction_n;
 mod turn_direction_np;
 mod turn_pp;
 mod turnable_np;
+mod u_turn_np;
 
+pub use cardinal_direction_n::{
+    CardinalDirectionNoun, ParseError as CardinalDirectionNounParseError,
+};
+pub use cardinal_pp::{CardinalPrepPhrase, ParseError as CardinalPrepPhraseParseError};
 pub use def_article::DefiniteArticle;
+pub use directions::{Directions, ParseError as DirectionsParseError};
 pub use distance_np::{DistanceNounPhrase, ParseError as DistanceNounPhraseParseError};
+pub use end_of_street_np::EndOfStreetNounPhrase;
 pub use forward_np::ForwardNounPhrase;
+pub use gender::Gender;
+pub use landmark_np::{LandmarkNounPhrase, ParseError as LandmarkNounPhraseParseError};
 pub use number::Number;
-pub use ordinality::{Ordinality, ParseError as OrdinalityParseError};
+pub use ordinality::{Ordinality, Position};
 pub use sentence::{ParseError as SentenceParseError, Sentence};
+pub use span::Span;
+pub use street_name::StreetName;
 pub use street_np::{ParseError as StreetNounPhraseParseError, StreetNounPhrase};
-pub use turn_direction_n::{ParseError as TurnDirectionNounParseError, TurnDirectionNoun};
+pub use turn_direction_n::TurnDirectionNoun;
 pub use turn_direction_np::{
     ParseError as TurnDirectionNounPhraseParseError, TurnDirectionNounPhrase,
 };
 pub use turn_pp::{LeftRightTurnPrepPhrase, ParseError as LeftRightTurnPrepPhraseParseError};
 pub use turnable_np::{ParseError as TurnableNounPhraseParseError, TurnableNounPhrase};
+pub use u_turn_np::{ParseError as UTurnNounPhraseParseError, UTurnNounPhrase};
@@ -1,7 +1,10 @@
 use crate::lang::Lexeme;
 use std::fmt::Display;
+use std::num::NonZeroUsize;
 use thiserror::Error;
 
+use super::Span;
+
 /// A number.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Number {
@@ -12,7 +15,20 @@ pub enum Number {
     /// * "uno"
     /// * "una"
     Unx(Unx),
+
+    /// The number twenty-one, which agrees in gender like the number one.
+    ///
+    /// Examples:
+    /// * "veintiún"
+    /// * "veintiuno"
+    /// * "veintiuna"
+    VeintiUnx(Unx),
+
+    /// Any other number written as a word.
     Mas(usize),
+
+    /// A number written with digits, such as "7".
+    Digits(usize),
 }
 
 impl Number {
@@ -40,16 +56,50 @@ impl Number {
         Self::Mas(4)
     }
 
+    /// Returns the number used to count a feminine noun, written as a word if
+    /// there is one.
+    pub fn fem(value: NonZeroUsize) -> Self {
+        match value.get() {
+            1 => Self::una(),
+            21 => Self::VeintiUnx(Unx::Una),
+            value if word(value).is_some() => Self::Mas(value),
+            value => Self::Digits(value),
+        }
+    }
+
+    pub fn value(&self) -> usize {
+        match self {
+            Number::Unx(_) => 1,
+            Number::VeintiUnx(_) => 21,
+            Number::Mas(value) | Number::Digits(value) => *value,
+        }
+    }
+
+    /// Returns `true` if the number may count a feminine noun. Only the forms
+    /// of one have a gender.
+    pub fn agrees_with_fem(&self) -> bool {
+        match self {
+            Number::Unx(unx) | Number::VeintiUnx(unx) => *unx == Unx::Una,
+            Number::Mas(_) | Number::Digits(_) => true,
+        }
+    }
+
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
-        let (first, rest) = lexemes.split_first().ok_or(ParseError())?;
+        let (first, rest) = lexemes.split_first().ok_or(ParseError(Span::new(0, 0)))?;
         match first {
             Lexeme::Un => Ok(Self::un()),
             Lexeme::Una => Ok(Self::una()),
             Lexeme::Uno => Ok(Self::uno()),
-            Lexeme::Dos => Ok(Self::dos()),
-            Lexeme::Tres => Ok(Self::tres()),
-            Lexeme::Quatro => Ok(Self::quatro()),
-            _ => Err(ParseError()),
+            Lexeme::Veintiún => Ok(Self::VeintiUnx(Unx::Un)),
+            Lexeme::Veintiuna => Ok(Self::VeintiUnx(Unx::Una)),
+            Lexeme::Veintiuno => Ok(Self::VeintiUnx(Unx::Uno)),
+            Lexeme::Numeral(n) if n.suffix().is_none() && n.value() > 0 => {
+                Ok(Self::Digits(n.value() as usize))
+            }
+            _ => (2..=MAX_WORD)
+                .find(|value| word(*value) == Some(*first))
+                .map(Self::Mas)
+                .ok_or(ParseError(Span::at(0))),
         }
         .map(|num| (num, rest))
     }
@@ -59,14 +109,62 @@ impl Display for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Number::Unx(unx) => write!(f, "{unx}"),
-            Number::Mas(2) => write!(f, "dos"),
-            Number::Mas(3) => write!(f, "tres"),
-            Number::Mas(4) => write!(f, "quatro"),
-            Number::Mas(n) => write!(f, "{n}"),
+            Number::VeintiUnx(unx) => {
+                let l = match unx {
+                    Unx::Un => Lexeme::Veintiún,
+                    Unx::Uno => Lexeme::Veintiuno,
+                    Unx::Una => Lexeme::Veintiuna,
+                };
+                write!(f, "{l}")
+            }
+            Number::Mas(n) => match word(*n) {
+                Some(l) => write!(f, "{l}"),
+                None => write!(f, "{n}"),
+            },
+            Number::Digits(n) => write!(f, "{n}"),
         }
     }
 }
 
+/// The largest number that can be written as a word.
+const MAX_WORD: usize = 29;
+
+/// Returns the word for a number other than one or twenty-one, whose words
+/// depend on gender.
+fn word(value: usize) -> Option<Lexeme> {
+    let l = match value {
+        2 => Lexeme::Dos,
+        3 => Lexeme::Tres,
+        4 => Lexeme::Quatro,
+        5 => Lexeme::Cinco,
+        6 => Lexeme::Seis,
+        7 => Lexeme::Siete,
+        8 => Lexeme::Ocho,
+        9 => Lexeme::Nueve,
+        10 => Lexeme::Diez,
+        11 => Lexeme::Once,
+        12 => Lexeme::Doce,
+        13 => Lexeme::Trece,
+        14 => Lexeme::Catorce,
+        15 => Lexeme::Quince,
+        16 => Lexeme::Dieciséis,
+        17 => Lexeme::Diecisiete,
+        18 => Lexeme::Dieciocho,
+        19 => Lexeme::Diecinueve,
+        20 => Lexeme::Veinte,
+        22 => Lexeme::Veintidós,
+        23 => Lexeme::Veintitrés,
+        24 => Lexeme::Veinticuatro,
+        25 => Lexeme::Veinticinco,
+        26 => Lexeme::Veintiséis,
+        27 => Lexeme::Veintisiete,
+        28 => Lexeme::Veintiocho,
+        29 => Lexeme::Veintinueve,
+        _ => return None,
+    };
+    Some(l)
+}
+
 /// Variations of the number one.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Unx {
@@ -88,4 +186,10 @@ impl Display for Unx {
 
 #[derive(Debug, Error)]
 #[error("The words(s) must be a number.")]
-pub struct ParseError();
+pub struct ParseError(Span);
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        self.0
+    }
+}
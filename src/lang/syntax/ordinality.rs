@@ -1,30 +1,95 @@
 use crate::lang::Lexeme;
+use std::fmt::Display;
 use thiserror::Error;
 
 use super::gender::{Gender, HasGender};
 
-/// The ordering of an item in a sequence.
+/// The ordering of an item in a sequence. Only positions that have a word,
+/// from the first to the tenth, can be represented.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Ordinality {
-    value: usize,
+    position: Position,
     gender: Gender,
 }
 
+/// Where an item is in a sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// The nth item, counting from one.
+    Nth(usize),
+
+    /// The last item.
+    Last,
+}
+
+/// The words for the first ten positions, feminine and masculine.
+const NTH_WORDS: [(Lexeme, Lexeme); 10] = [
+    (Lexeme::Primera, Lexeme::Primero),
+    (Lexeme::Segunda, Lexeme::Segundo),
+    (Lexeme::Tercera, Lexeme::Tercero),
+    (Lexeme::Cuarta, Lexeme::Cuarto),
+    (Lexeme::Quinta, Lexeme::Quinto),
+    (Lexeme::Sexta, Lexeme::Sexto),
+    (Lexeme::Séptima, Lexeme::Séptimo),
+    (Lexeme::Octava, Lexeme::Octavo),
+    (Lexeme::Novena, Lexeme::Noveno),
+    (Lexeme::Décima, Lexeme::Décimo),
+];
+
 impl Ordinality {
-    pub fn new(value: usize, gender: Gender) -> Self {
-        Self { value, gender }
+    /// Returns the ordinality of the nth item, or `None` if there is no word
+    /// for it.
+    pub fn new(value: usize, gender: Gender) -> Option<Self> {
+        (1..=NTH_WORDS.len()).contains(&value).then_some(Self {
+            position: Position::Nth(value),
+            gender,
+        })
     }
 
-    pub fn new_masc(value: usize) -> Self {
+    pub fn new_masc(value: usize) -> Option<Self> {
         Self::new(value, Gender::Masculine)
     }
 
-    pub fn new_fem(value: usize) -> Self {
+    pub fn new_fem(value: usize) -> Option<Self> {
         Self::new(value, Gender::Feminine)
     }
 
-    pub fn value(&self) -> usize {
-        self.value
+    pub fn first(gender: Gender) -> Self {
+        Self {
+            position: Position::Nth(1),
+            gender,
+        }
+    }
+
+    pub fn last(gender: Gender) -> Self {
+        Self {
+            position: Position::Last,
+            gender,
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the same ordinality with the given gender.
+    pub fn with_gender(&self, gender: Gender) -> Self {
+        Self {
+            position: self.position,
+            gender,
+        }
+    }
+
+    /// Returns the lexeme for the ordinality.
+    pub fn lexeme(&self) -> Lexeme {
+        let (fem, masc) = match self.position {
+            Position::Nth(value) => NTH_WORDS[value - 1],
+            Position::Last => (Lexeme::Última, Lexeme::Último),
+        };
+        match self.gender {
+            Gender::Feminine => fem,
+            Gender::Masculine => masc,
+        }
     }
 }
 
@@ -32,20 +97,31 @@ impl Ordinality {
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
         let (l, rest) = lexemes.split_first().ok_or(ParseError::NoLexemes)?;
         let ord = match l {
-            Lexeme::Primera => Ok(Self::new_fem(1)),
-            Lexeme::Primero => Ok(Self::new_masc(1)),
-            Lexeme::Segunda => Ok(Self::new_fem(2)),
-            Lexeme::Segundo => Ok(Self::new_masc(2)),
-            Lexeme::Tercera => Ok(Self::new_fem(3)),
-            Lexeme::Tercero => Ok(Self::new_masc(3)),
-            Lexeme::Cuarta => Ok(Self::new_fem(4)),
-            Lexeme::Cuarto => Ok(Self::new_masc(4)),
-            _ => Err(ParseError::NotOrdinality),
-        }?;
+            // "próxima" and "siguiente" are synonyms of "primera". "siguiente"
+            // has the same form for both genders, and is read as feminine
+            // since it only describes streets.
+            Lexeme::Próxima | Lexeme::Siguiente => Self::first(Gender::Feminine),
+            Lexeme::Próximo => Self::first(Gender::Masculine),
+            _ => [Gender::Feminine, Gender::Masculine]
+                .into_iter()
+                .flat_map(|gender| {
+                    (1..=NTH_WORDS.len())
+                        .filter_map(move |value| Self::new(value, gender))
+                        .chain([Self::last(gender)])
+                })
+                .find(|ord| ord.lexeme() == *l)
+                .ok_or(ParseError::NotOrdinality)?,
+        };
         Ok((ord, rest))
     }
 }
 
+impl Display for Ordinality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lexeme())
+    }
+}
+
 impl HasGender for Ordinality {
     fn gender(&self) -> Gender {
         self.gender
@@ -59,3 +135,16 @@ pub enum ParseError {
     #[error("There are no words.")]
     NoLexemes,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Ordinality;
+    use crate::lang::Lexeme;
+
+    #[test]
+    fn only_positions_with_words() {
+        assert_eq!(Ordinality::new_fem(0), None);
+        assert_eq!(Ordinality::new_fem(11), None);
+        assert_eq!(Ordinality::new_masc(10).unwrap().lexeme(), Lexeme::Décimo);
+    }
+}
@@ -29,3 +29,9 @@ pub fn consume_lexeme(lexemes: &[Lexeme], to_consume: Lexeme) -> Option<((), &[L
         .filter(|(&l, _rest)| l == to_consume)
         .map(|(_l, rest)| ((), rest))
 }
+
+/// Returns the index into `lexemes` at which `rest` starts, where `rest` is a
+/// suffix of `lexemes`.
+pub fn offset(lexemes: &[Lexeme], rest: &[Lexeme]) -> usize {
+    lexemes.len() - rest.len()
+}
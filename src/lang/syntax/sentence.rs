@@ -1,21 +1,29 @@
 use crate::lang::Lexeme;
+use std::fmt::Display;
 use thiserror::Error;
 
 use super::{
-    parse, DistanceNounPhrase, DistanceNounPhraseParseError, ForwardNounPhrase,
-    LeftRightTurnPrepPhrase, LeftRightTurnPrepPhraseParseError, StreetNounPhrase,
-    StreetNounPhraseParseError, TurnableNounPhrase, TurnableNounPhraseParseError,
+    gender::HasGender, parse, CardinalPrepPhrase, CardinalPrepPhraseParseError, DistanceNounPhrase,
+    DistanceNounPhraseParseError, EndOfStreetNounPhrase, ForwardNounPhrase, LandmarkNounPhrase,
+    LandmarkNounPhraseParseError, LeftRightTurnPrepPhrase, LeftRightTurnPrepPhraseParseError, Span,
+    StreetNounPhrase, StreetNounPhraseParseError, TurnableNounPhrase, TurnableNounPhraseParseError,
+    UTurnNounPhrase, UTurnNounPhraseParseError,
 };
 
 /// A sentence which is either a delcaration of where something is or a command
 /// instructing how to navigate to a destination.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Sentence {
     /// Examples:
     /// * "Está a la derecha."
     /// * "Está a mano derecha."
     EstáTurnPp(LeftRightTurnPrepPhrase),
 
+    /// Examples:
+    /// * "Está a la izquierda enfrente de la casa."
+    /// * "Está a mano derecha enfrente del parque."
+    EstáTurnPpEnfrenteNp(LeftRightTurnPrepPhrase, LandmarkNounPhrase),
+
     /// Extamples:
     /// * "Está en la segunda calle a la derecha."
     /// * "Está en la calle a la izquierda."
@@ -26,6 +34,20 @@ pub enum Sentence {
     /// * "Gira a mano izquierda."
     GiraPp(LeftRightTurnPrepPhrase),
 
+    /// Examples:
+    /// * "Gira hacia el este."
+    /// * "Gira al norte."
+    GiraCardinalPp(CardinalPrepPhrase),
+
+    /// Examples:
+    /// * "Gira a la derecha después del supermercado."
+    /// * "Gira a la izquierda después de la tienda."
+    GiraPpDespuésNp(LeftRightTurnPrepPhrase, LandmarkNounPhrase),
+
+    /// Examples:
+    /// * "Gira a la izquierda al final de la calle."
+    GiraPpAlFinalNp(LeftRightTurnPrepPhrase, EndOfStreetNounPhrase),
+
     /// Examples:
     /// * "Gira la segunda calle a la derecha."
     /// * "Gira la calle a la izquierda."
@@ -45,87 +67,340 @@ pub enum Sentence {
     /// Examples:
     /// * Continúa todo derecho hasta la primera calle.
     /// * Continúa derecho hasta la segunda calle.
-    ///
-    /// Not supported but should be:
     /// * Continúa derecho hasta la segunda calle a la izquierda.
-    ContinúaNpHastaNp(ForwardNounPhrase, StreetNounPhrase),
-}
+    /// * Continúa hasta la Calle 87.
+    ContinúaNpHastaNp(
+        Option<ForwardNounPhrase>,
+        StreetNounPhrase,
+        Option<LeftRightTurnPrepPhrase>,
+    ),
 
-impl Sentence {
-    pub fn parse(lexemes: &[Lexeme]) -> Result<Self, ParseError> {
-        let (s, rest) = Self::try_parse(lexemes)?;
+    /// Examples:
+    /// * Continúa hasta el parque.
+    /// * Continúa todo derecho hasta la tienda.
+    ContinúaNpHastaLandmarkNp(Option<ForwardNounPhrase>, LandmarkNounPhrase),
 
-        if rest.is_empty() {
-            Ok(s)
-        } else {
-            Err(ParseError::LexemesAfterPrepositinalPhrase(rest.to_vec()))
-        }
-    }
+    /// Examples:
+    /// * Continúa hasta el final de la calle.
+    /// * Continúa todo derecho hasta el final de la calle.
+    ContinúaNpHastaFinalNp(Option<ForwardNounPhrase>, EndOfStreetNounPhrase),
+
+    /// Examples:
+    /// * "Camina dos quadras."
+    /// * "Camina todo derecho tres calles."
+    CaminaNpNp(Option<ForwardNounPhrase>, DistanceNounPhrase),
 
+    /// Examples:
+    /// * "Cruza dos calles."
+    /// * "Cruza una calle."
+    CruzaNp(DistanceNounPhrase),
+
+    /// Examples:
+    /// * "Pasa dos calles."
+    PasaNp(DistanceNounPhrase),
+
+    /// Examples:
+    /// * "Da la vuelta."
+    /// * "Da media vuelta."
+    DaNp(UTurnNounPhrase),
+
+    /// Examples:
+    /// * "Regresa por donde viniste."
+    /// * "Regrese por donde vino."
+    RegresaPorDondeViniste,
+
+    /// Examples:
+    /// * "Ve al norte."
+    /// * "Ve hacia el oeste."
+    VeCardinalPp(CardinalPrepPhrase),
+
+    /// Examples:
+    /// * "Ve dos quadras."
+    /// * "Ve derecho una calle."
+    VeNpNp(Option<ForwardNounPhrase>, DistanceNounPhrase),
+}
+
+impl Sentence {
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
         let (first, rest) = lexemes.split_first().ok_or(ParseError::NoWords)?;
         match first {
             Lexeme::Está => Self::try_parse_está(rest),
-            Lexeme::Toma => Self::try_parse_toma(rest),
-            Lexeme::Gira => Self::try_parse_gira(rest),
-            Lexeme::Continúa => Self::try_parse_continúa(rest),
+            // The usted and vos forms of a verb mean the same as its tú form.
+            Lexeme::Toma | Lexeme::Tome | Lexeme::Tomá => Self::try_parse_toma(rest),
+            Lexeme::Gira
+            | Lexeme::Gire
+            | Lexeme::Girá
+            | Lexeme::Dobla
+            | Lexeme::Doble
+            | Lexeme::Doblá => Self::try_parse_gira(rest),
+            Lexeme::Continúa | Lexeme::Continúe | Lexeme::Sigue | Lexeme::Siga | Lexeme::Seguí => {
+                Self::try_parse_continúa(rest)
+            }
+            Lexeme::Camina | Lexeme::Camine | Lexeme::Caminá => Self::try_parse_camina(rest),
+            Lexeme::Cruza | Lexeme::Cruce | Lexeme::Cruzá => Self::try_parse_cruza(rest),
+            Lexeme::Pasa | Lexeme::Pase | Lexeme::Pasá => Self::try_parse_pasa(rest),
+            Lexeme::Da | Lexeme::Dé => Self::try_parse_da(rest),
+            Lexeme::Regresa | Lexeme::Regrese | Lexeme::Regresá => Self::try_parse_regresa(rest),
+            Lexeme::Ve | Lexeme::Vaya | Lexeme::Andá => Self::try_parse_ve(rest),
             _ => Err(ParseError::NonInitialVerb(*first)),
         }
     }
 
     fn try_parse_está(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
         if let Some(((), rest)) = parse::consume_lexeme(lexemes, Lexeme::En) {
-            let (np, rest) =
-                TurnableNounPhrase::try_parse(rest).map_err(ParseError::EstáEnNpPpFirst)?;
-            let (pp, rest) =
-                LeftRightTurnPrepPhrase::try_parse(rest).map_err(ParseError::EstáEnNpPpSecond)?;
+            let (np, rest) = TurnableNounPhrase::try_parse(rest)
+                .map_err(|e| ParseError::EstáEnNpPpFirst(parse::offset(lexemes, rest), e))?;
+            let (pp, rest) = LeftRightTurnPrepPhrase::try_parse(rest)
+                .map_err(|e| ParseError::EstáEnNpPpSecond(parse::offset(lexemes, rest), e))?;
             Ok((Self::EstáEnNpPp(np, pp), rest))
         } else {
-            let (pp, rest) =
-                LeftRightTurnPrepPhrase::try_parse(lexemes).map_err(ParseError::EstáTurnPpFirst)?;
-            Ok((Self::EstáTurnPp(pp), rest))
+            let (pp, rest) = LeftRightTurnPrepPhrase::try_parse(lexemes)
+                .map_err(|e| ParseError::EstáTurnPpFirst(0, e))?;
+
+            match parse::consume_lexeme(rest, Lexeme::Enfrente) {
+                Some(((), rest)) => {
+                    let (np, rest) = LandmarkNounPhrase::try_parse_de(rest).map_err(|e| {
+                        ParseError::EstáTurnPpEnfrenteNpSecond(parse::offset(lexemes, rest), e)
+                    })?;
+                    Ok((Self::EstáTurnPpEnfrenteNp(pp, np), rest))
+                }
+                None => Ok((Self::EstáTurnPp(pp), rest)),
+            }
         }
     }
 
     fn try_parse_toma(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
         let (np, rest) =
-            TurnableNounPhrase::try_parse(lexemes).map_err(ParseError::TomaNpPpFirst)?;
-        let (pp, rest) =
-            LeftRightTurnPrepPhrase::try_parse(rest).map_err(ParseError::TomaNpPpSecond)?;
+            TurnableNounPhrase::try_parse(lexemes).map_err(|e| ParseError::TomaNpPpFirst(0, e))?;
+        let (pp, rest) = LeftRightTurnPrepPhrase::try_parse(rest)
+            .map_err(|e| ParseError::TomaNpPpSecond(parse::offset(lexemes, rest), e))?;
         Ok((Self::TomaNpPp(np, pp), rest))
     }
 
     fn try_parse_gira(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        if let Ok((pp, rest)) = CardinalPrepPhrase::try_parse(lexemes) {
+            return Ok((Self::GiraCardinalPp(pp), rest));
+        }
+
         if let Ok((np, rest)) = TurnableNounPhrase::try_parse(lexemes) {
-            let (pp, rest) =
-                LeftRightTurnPrepPhrase::try_parse(rest).map_err(ParseError::GiraNpPpSecond)?;
+            let (pp, rest) = LeftRightTurnPrepPhrase::try_parse(rest)
+                .map_err(|e| ParseError::GiraNpPpSecond(parse::offset(lexemes, rest), e))?;
             Ok((Self::GiraNpPp(np, pp), rest))
         } else {
-            let (pp, rest) =
-                LeftRightTurnPrepPhrase::try_parse(lexemes).map_err(ParseError::GiraPpFirst)?;
-            Ok((Self::GiraPp(pp), rest))
+            let (pp, rest) = LeftRightTurnPrepPhrase::try_parse(lexemes)
+                .map_err(|e| ParseError::GiraPpFirst(0, e))?;
+
+            if let Ok((np, rest)) = EndOfStreetNounPhrase::try_parse_al(rest) {
+                return Ok((Self::GiraPpAlFinalNp(pp, np), rest));
+            }
+
+            // "después" may either introduce a landmark or join this sentence
+            // to the next one.
+            let landmark = parse::consume_lexeme(rest, Lexeme::Después)
+                .and_then(|((), rest)| LandmarkNounPhrase::try_parse_de(rest).ok());
+            match landmark {
+                Some((np, rest)) => Ok((Self::GiraPpDespuésNp(pp, np), rest)),
+                None => Ok((Self::GiraPp(pp), rest)),
+            }
         }
     }
 
     fn try_parse_continúa(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
-        let (f_np, rest) =
-            ForwardNounPhrase::try_parse(lexemes).map_err(|_| ParseError::ContinúaNpXFirst)?;
+        // The forward noun phrase may only be omitted before "hasta".
+        let (f_np, rest) = Self::try_parse_optional_forward(lexemes);
 
         match parse::consume_lexeme(rest, Lexeme::Hasta) {
             Some(((), rest)) => {
-                let (s_np, rest) = StreetNounPhrase::try_parse(rest)
-                    .map_err(ParseError::ContinúaNpHastaNpSecond)?;
-                Ok((Self::ContinúaNpHastaNp(f_np, s_np), rest))
+                if let Ok((e_np, rest)) = EndOfStreetNounPhrase::try_parse(rest) {
+                    return Ok((Self::ContinúaNpHastaFinalNp(f_np, e_np), rest));
+                }
+
+                if let Ok((l_np, rest)) = LandmarkNounPhrase::try_parse(rest) {
+                    return Ok((Self::ContinúaNpHastaLandmarkNp(f_np, l_np), rest));
+                }
+
+                let (s_np, rest) = StreetNounPhrase::try_parse(rest).map_err(|e| {
+                    ParseError::ContinúaNpHastaNpSecond(parse::offset(lexemes, rest), e)
+                })?;
+
+                match LeftRightTurnPrepPhrase::try_parse(rest) {
+                    Ok((pp, rest)) => Ok((Self::ContinúaNpHastaNp(f_np, s_np, Some(pp)), rest)),
+                    Err(_) => Ok((Self::ContinúaNpHastaNp(f_np, s_np, None), rest)),
+                }
             }
             None => {
-                let (d_np, rest) =
-                    DistanceNounPhrase::try_parse(rest).map_err(ParseError::ContinúaNpNpSecond)?;
+                let f_np = f_np.ok_or(ParseError::ContinúaNpXFirst)?;
+                let (d_np, rest) = DistanceNounPhrase::try_parse(rest).map_err(|e| {
+                    ParseError::ContinúaNpNpSecond(parse::offset(lexemes, rest), e)
+                })?;
                 Ok((Sentence::ContinúaNpNp(f_np, d_np), rest))
             }
         }
     }
+
+    fn try_parse_camina(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (f_np, rest) = Self::try_parse_optional_forward(lexemes);
+        let (d_np, rest) = DistanceNounPhrase::try_parse(rest)
+            .map_err(|e| ParseError::CaminaNpNpSecond(parse::offset(lexemes, rest), e))?;
+        Ok((Self::CaminaNpNp(f_np, d_np), rest))
+    }
+
+    fn try_parse_cruza(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (np, rest) =
+            DistanceNounPhrase::try_parse(lexemes).map_err(|e| ParseError::CruzaNpFirst(0, e))?;
+        Ok((Self::CruzaNp(np), rest))
+    }
+
+    fn try_parse_pasa(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (np, rest) =
+            DistanceNounPhrase::try_parse(lexemes).map_err(|e| ParseError::PasaNpFirst(0, e))?;
+        Ok((Self::PasaNp(np), rest))
+    }
+
+    fn try_parse_da(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (np, rest) =
+            UTurnNounPhrase::try_parse(lexemes).map_err(|e| ParseError::DaNpFirst(0, e))?;
+        Ok((Self::DaNp(np), rest))
+    }
+
+    fn try_parse_regresa(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let ((), rest) = parse::consume_lexeme(lexemes, Lexeme::Por)
+            .and_then(|((), rest)| parse::consume_lexeme(rest, Lexeme::Donde))
+            .and_then(|((), rest)| {
+                parse::consume_lexeme(rest, Lexeme::Viniste)
+                    .or_else(|| parse::consume_lexeme(rest, Lexeme::Vino))
+            })
+            .ok_or(ParseError::RegresaPorDondeViniste)?;
+        Ok((Self::RegresaPorDondeViniste, rest))
+    }
+
+    fn try_parse_ve(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let cardinal_err = match CardinalPrepPhrase::try_parse(lexemes) {
+            Ok((pp, rest)) => return Ok((Self::VeCardinalPp(pp), rest)),
+            Err(e) => e,
+        };
+
+        let (f_np, rest) = Self::try_parse_optional_forward(lexemes);
+        match DistanceNounPhrase::try_parse(rest) {
+            Ok((d_np, rest)) => Ok((Self::VeNpNp(f_np, d_np), rest)),
+            // Without "derecho" or a distance noun, the player most likely
+            // meant to name a cardinal direction.
+            Err(DistanceNounPhraseParseError::MissingDistanceNoun(_)) if f_np.is_none() => {
+                Err(ParseError::VeCardinalPpFirst(0, cardinal_err))
+            }
+            Err(e) => Err(ParseError::VeNpNpSecond(parse::offset(lexemes, rest), e)),
+        }
+    }
+
+    /// Parses "derecho" or "todo derecho" if the lexemes start with either.
+    fn try_parse_optional_forward(lexemes: &[Lexeme]) -> (Option<ForwardNounPhrase>, &[Lexeme]) {
+        match ForwardNounPhrase::try_parse(lexemes) {
+            Ok((f_np, rest)) => (Some(f_np), rest),
+            Err(_) => (None, lexemes),
+        }
+    }
+}
+
+impl Display for Sentence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sentence::EstáTurnPp(pp) => write!(f, "{} {pp}", Lexeme::Está),
+            Sentence::EstáTurnPpEnfrenteNp(pp, np) => {
+                write!(f, "{} {pp} {} {}", Lexeme::Está, Lexeme::Enfrente, De(np))
+            }
+            Sentence::EstáEnNpPp(np, pp) => write!(f, "{} {} {np} {pp}", Lexeme::Está, Lexeme::En),
+            Sentence::GiraPp(pp) => write!(f, "{} {pp}", Lexeme::Gira),
+            Sentence::GiraCardinalPp(pp) => write!(f, "{} {pp}", Lexeme::Gira),
+            Sentence::GiraPpDespuésNp(pp, np) => {
+                write!(f, "{} {pp} {} {}", Lexeme::Gira, Lexeme::Después, De(np))
+            }
+            Sentence::GiraPpAlFinalNp(pp, _) => write!(
+                f,
+                "{} {pp} {} {} {} {} {}",
+                Lexeme::Gira,
+                Lexeme::Al,
+                Lexeme::Final,
+                Lexeme::De,
+                Lexeme::La,
+                Lexeme::Calle
+            ),
+            Sentence::GiraNpPp(np, pp) => write!(f, "{} {np} {pp}", Lexeme::Gira),
+            Sentence::TomaNpPp(np, pp) => write!(f, "{} {np} {pp}", Lexeme::Toma),
+            Sentence::ContinúaNpNp(f_np, d_np) => write!(f, "{} {f_np} {d_np}", Lexeme::Continúa),
+            Sentence::ContinúaNpHastaNp(f_np, s_np, pp) => {
+                write!(f, "{}", Lexeme::Continúa)?;
+                if let Some(f_np) = f_np {
+                    write!(f, " {f_np}")?;
+                }
+                write!(f, " {} {s_np}", Lexeme::Hasta)?;
+                if let Some(pp) = pp {
+                    write!(f, " {pp}")?;
+                }
+                Ok(())
+            }
+            Sentence::ContinúaNpHastaLandmarkNp(f_np, l_np) => {
+                write!(f, "{}", Lexeme::Continúa)?;
+                if let Some(f_np) = f_np {
+                    write!(f, " {f_np}")?;
+                }
+                write!(f, " {} {l_np}", Lexeme::Hasta)
+            }
+            Sentence::ContinúaNpHastaFinalNp(f_np, e_np) => {
+                write!(f, "{}", Lexeme::Continúa)?;
+                if let Some(f_np) = f_np {
+                    write!(f, " {f_np}")?;
+                }
+                write!(f, " {} {e_np}", Lexeme::Hasta)
+            }
+            Sentence::CaminaNpNp(f_np, d_np) => {
+                write!(f, "{}", Lexeme::Camina)?;
+                if let Some(f_np) = f_np {
+                    write!(f, " {f_np}")?;
+                }
+                write!(f, " {d_np}")
+            }
+            Sentence::CruzaNp(np) => write!(f, "{} {np}", Lexeme::Cruza),
+            Sentence::PasaNp(np) => write!(f, "{} {np}", Lexeme::Pasa),
+            Sentence::DaNp(np) => write!(f, "{} {np}", Lexeme::Da),
+            Sentence::RegresaPorDondeViniste => write!(
+                f,
+                "{} {} {} {}",
+                Lexeme::Regresa,
+                Lexeme::Por,
+                Lexeme::Donde,
+                Lexeme::Viniste
+            ),
+            Sentence::VeCardinalPp(pp) => write!(f, "{} {pp}", Lexeme::Ve),
+            Sentence::VeNpNp(f_np, d_np) => {
+                write!(f, "{}", Lexeme::Ve)?;
+                if let Some(f_np) = f_np {
+                    write!(f, " {f_np}")?;
+                }
+                write!(f, " {d_np}")
+            }
+        }
+    }
 }
 
+/// Displays a landmark noun phrase after the preposition "de", contracting
+/// "de el" to "del".
+struct De<'a>(&'a LandmarkNounPhrase);
+
+impl Display for De<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let noun = self.0 .0;
+        if noun.is_masc() {
+            write!(f, "{} {}", Lexeme::Del, noun.lexeme())
+        } else {
+            write!(f, "{} {}", Lexeme::De, self.0)
+        }
+    }
+}
+
+/// An error that occurs trying to parse a sentence.
+///
+/// Variants that wrap the error of a phrase also store the index at which
+/// that phrase starts, counting from the lexeme after the verb.
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("The sentence must contain words.")]
@@ -134,36 +409,151 @@ pub enum ParseError {
     #[error("The sentence must start with a verb.")]
     NonInitialVerb(Lexeme),
 
-    #[error("EstáTurnPpFirst: {0}")]
-    EstáTurnPpFirst(LeftRightTurnPrepPhraseParseError),
+    #[error("EstáTurnPpFirst: {1}")]
+    EstáTurnPpFirst(usize, #[source] LeftRightTurnPrepPhraseParseError),
 
-    #[error("EstáEnNpPpFirst: {0}")]
-    EstáEnNpPpFirst(TurnableNounPhraseParseError),
+    #[error("EstáTurnPpEnfrenteNpSecond: {1}")]
+    EstáTurnPpEnfrenteNpSecond(usize, #[source] LandmarkNounPhraseParseError),
 
-    #[error("EstáEnNpPpSecond: {0}")]
-    EstáEnNpPpSecond(LeftRightTurnPrepPhraseParseError),
+    #[error("EstáEnNpPpFirst: {1}")]
+    EstáEnNpPpFirst(usize, #[source] TurnableNounPhraseParseError),
 
-    #[error("TomaNpPpFirst: {0}")]
-    TomaNpPpFirst(TurnableNounPhraseParseError),
+    #[error("EstáEnNpPpSecond: {1}")]
+    EstáEnNpPpSecond(usize, #[source] LeftRightTurnPrepPhraseParseError),
 
-    #[error("TomaNpPpSecond: {0}")]
-    TomaNpPpSecond(LeftRightTurnPrepPhraseParseError),
+    #[error("TomaNpPpFirst: {1}")]
+    TomaNpPpFirst(usize, #[source] TurnableNounPhraseParseError),
 
-    #[error("GiraPpFirst: {0}")]
-    GiraPpFirst(LeftRightTurnPrepPhraseParseError),
+    #[error("TomaNpPpSecond: {1}")]
+    TomaNpPpSecond(usize, #[source] LeftRightTurnPrepPhraseParseError),
 
-    #[error("GiraNpPpSecond: {0}")]
-    GiraNpPpSecond(LeftRightTurnPrepPhraseParseError),
+    #[error("GiraPpFirst: {1}")]
+    GiraPpFirst(usize, #[source] LeftRightTurnPrepPhraseParseError),
 
-    #[error("ContinúaNpXFirst")]
+    #[error("GiraNpPpSecond: {1}")]
+    GiraNpPpSecond(usize, #[source] LeftRightTurnPrepPhraseParseError),
+
+    #[error(r#""continúa" must be followed by "derecho", "todo derecho" or "hasta"."#)]
     ContinúaNpXFirst,
 
-    #[error("ContinúaNpNpSecond: {0}")]
-    ContinúaNpNpSecond(DistanceNounPhraseParseError),
+    #[error("ContinúaNpNpSecond: {1}")]
+    ContinúaNpNpSecond(usize, #[source] DistanceNounPhraseParseError),
+
+    #[error("ContinúaNpHastaNp: {1}")]
+    ContinúaNpHastaNpSecond(usize, #[source] StreetNounPhraseParseError),
+
+    #[error("CaminaNpNpSecond: {1}")]
+    CaminaNpNpSecond(usize, #[source] DistanceNounPhraseParseError),
 
-    #[error("ContinúaNpHastaNp: {0}")]
-    ContinúaNpHastaNpSecond(StreetNounPhraseParseError),
+    #[error("CruzaNpFirst: {1}")]
+    CruzaNpFirst(usize, #[source] DistanceNounPhraseParseError),
+
+    #[error("PasaNpFirst: {1}")]
+    PasaNpFirst(usize, #[source] DistanceNounPhraseParseError),
+
+    #[error("DaNpFirst: {1}")]
+    DaNpFirst(usize, #[source] UTurnNounPhraseParseError),
+
+    #[error(r#""regresa" must be followed by "por donde viniste"."#)]
+    RegresaPorDondeViniste,
+
+    #[error("VeCardinalPpFirst: {1}")]
+    VeCardinalPpFirst(usize, #[source] CardinalPrepPhraseParseError),
+
+    #[error("VeNpNpSecond: {1}")]
+    VeNpNpSecond(usize, #[source] DistanceNounPhraseParseError),
+}
 
-    #[error("The sentence contains unxpected words after the prepositional phrase.")]
-    LexemesAfterPrepositinalPhrase(Vec<Lexeme>),
+impl ParseError {
+    pub fn span(&self) -> Span {
+        // Every phrase follows the verb, which is the first lexeme.
+        match self {
+            ParseError::NoWords => Span::new(0, 0),
+            ParseError::NonInitialVerb(_) => Span::at(0),
+            ParseError::EstáTurnPpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::EstáTurnPpEnfrenteNpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::EstáEnNpPpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::EstáEnNpPpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::TomaNpPpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::TomaNpPpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::GiraPpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::GiraNpPpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::ContinúaNpXFirst => Span::at(1),
+            ParseError::ContinúaNpNpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::ContinúaNpHastaNpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::CaminaNpNpSecond(offset, e) => e.span().offset(offset + 1),
+            ParseError::CruzaNpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::PasaNpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::DaNpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::RegresaPorDondeViniste => Span::new(1, 4),
+            ParseError::VeCardinalPpFirst(offset, e) => e.span().offset(offset + 1),
+            ParseError::VeNpNpSecond(offset, e) => e.span().offset(offset + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sentence;
+    use crate::lang::{
+        syntax::{
+            EndOfStreetNounPhrase, ForwardNounPhrase, LeftRightTurnPrepPhrase, Ordinality,
+            StreetNounPhrase, TurnDirectionNoun, TurnDirectionNounPhrase, UTurnNounPhrase,
+        },
+        Lexeme,
+    };
+
+    #[test]
+    fn parse_continúa_hasta_np_pp() {
+        let lexemes =
+            Lexeme::parse_line("Continúa derecho hasta la segunda calle a la izquierda").unwrap();
+        let (s, rest) = Sentence::try_parse(&lexemes).unwrap();
+
+        let izquierda = TurnDirectionNounPhrase(TurnDirectionNoun::Izquierda);
+        let expected = Sentence::ContinúaNpHastaNp(
+            Some(ForwardNounPhrase()),
+            StreetNounPhrase::LaCalleOrd(Ordinality::new_fem(2).unwrap()),
+            Some(LeftRightTurnPrepPhrase(izquierda)),
+        );
+        assert_eq!(s, expected);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn parse_end_of_street() {
+        let parse = |line| {
+            Sentence::try_parse(&Lexeme::parse_line(line).unwrap())
+                .unwrap()
+                .0
+        };
+        assert_eq!(
+            parse("continúa hasta el final de la calle"),
+            Sentence::ContinúaNpHastaFinalNp(None, EndOfStreetNounPhrase())
+        );
+
+        let izquierda = TurnDirectionNounPhrase(TurnDirectionNoun::Izquierda);
+        assert_eq!(
+            parse("gira a la izquierda al final de la calle"),
+            Sentence::GiraPpAlFinalNp(LeftRightTurnPrepPhrase(izquierda), EndOfStreetNounPhrase())
+        );
+    }
+
+    #[test]
+    fn parse_u_turns() {
+        let parse = |line| Sentence::try_parse(&Lexeme::parse_line(line).unwrap()).map(|(s, _)| s);
+        assert_eq!(
+            parse("da la vuelta").unwrap(),
+            Sentence::DaNp(UTurnNounPhrase::LaVuelta)
+        );
+        assert_eq!(
+            parse("da media vuelta").unwrap(),
+            Sentence::DaNp(UTurnNounPhrase::MediaVuelta)
+        );
+        assert_eq!(
+            parse("regresa por donde viniste").unwrap(),
+            Sentence::RegresaPorDondeViniste
+        );
+        assert!(parse("da vuelta").is_err());
+        assert!(parse("regresa por donde").is_err());
+    }
 }
@@ -0,0 +1,23 @@
+/// A range of lexeme indices, `start..end`, within a sequence of lexemes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns a span covering the single lexeme at the given index.
+    pub fn at(idx: usize) -> Self {
+        Self::new(idx, idx + 1)
+    }
+
+    /// Returns the same span shifted to the right by the given number of
+    /// lexemes.
+    pub fn offset(&self, n: usize) -> Self {
+        Self::new(self.start + n, self.end + n)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::lang::Lexeme;
+use iter_tools::Itertools;
+use std::fmt::Display;
+use thiserror::Error;
+
+/// The proper name of a street. It is made up of one or more nouns describing
+/// the kind of street followed by an identifier.
+///
+/// Examples:
+/// * "Calle 87"
+/// * "Carrera 19b"
+/// * "Avenida Calle 85"
+/// * "Autopista Norte"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreetName(Vec<Lexeme>);
+
+impl StreetName {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let kinds_len = lexemes.iter().take_while(|l| Self::is_kind(l)).count();
+        if kinds_len == 0 {
+            return Err(ParseError::MissingStreetKind);
+        }
+
+        let (kinds, rest) = lexemes.split_at(kinds_len);
+        let (id, rest) = rest
+            .split_first()
+            .filter(|(l, _rest)| is_identifier(l))
+            .ok_or(ParseError::MissingIdentifier)?;
+
+        let mut name = kinds.to_vec();
+        name.push(*id);
+        Ok((Self(name), rest))
+    }
+
+    /// Returns whether the lexeme names a kind of street, such as "calle",
+    /// which is followed by the street's identifier.
+    pub fn is_kind(l: &Lexeme) -> bool {
+        matches!(
+            l,
+            Lexeme::Calle | Lexeme::Carrera | Lexeme::Avenida | Lexeme::Autopista
+        )
+    }
+}
+
+impl Display for StreetName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self
+            .0
+            .iter()
+            .map(|l| {
+                let word = l.to_string();
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+                    None => word,
+                }
+            })
+            .join(" ");
+        write!(f, "{s}")
+    }
+}
+
+fn is_identifier(l: &Lexeme) -> bool {
+    matches!(
+        l,
+        Lexeme::Numeral(_) | Lexeme::Norte | Lexeme::Sur | Lexeme::Este | Lexeme::Oeste
+    )
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error(r#"A street name must start with "calle", "carrera", "avenida" or "autopista"."#)]
+    MissingStreetKind,
+
+    #[error("A street name must end with a number or a direction.")]
+    MissingIdentifier,
+}
@@ -1,23 +1,36 @@
+use std::fmt::Display;
 use thiserror::Error;
 
-use super::{gender::HasGender, DefiniteArticle, Ordinality};
+use super::{
+    gender::{Gender, HasGender},
+    DefiniteArticle, Ordinality, Span, StreetName,
+};
 use crate::lang::Lexeme;
 
 /// A noun phrase describing a specific street.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StreetNounPhrase {
     LaCalle,
     LaCalleOrd(Ordinality),
+
+    /// Examples:
+    /// * "la Calle 87"
+    /// * "la Carrera 19b"
+    Named(StreetName),
     // TODO:
     // LaCalle(Option<Ordinality>, Option<TurnPp>)
 }
 
 impl StreetNounPhrase {
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        if let Some((name, rest)) = Self::try_parse_named(lexemes) {
+            return Ok((Self::Named(name), rest));
+        }
+
         let idx = lexemes
             .iter()
             .position(|&l| l == Lexeme::Calle)
-            .ok_or(ParseError::MissingCalle)?;
+            .ok_or(ParseError::MissingCalle(Span::new(0, lexemes.len())))?;
         let (before_calle, after_incl_calle) = lexemes.split_at(idx);
 
         // Try to get the article from the start of the lexemes.
@@ -40,11 +53,14 @@ impl StreetNounPhrase {
         } else {
             // If there are lexemes between "la" and "calle" then they must be
             // an ordinality.
-            let (ord, after_ord) = Ordinality::try_parse(before_calle)
-                .map_err(|_| ParseError::LexemesBeforeCalleNotOrdinality)?;
+            let not_ordinality = ParseError::LexemesBeforeCalleNotOrdinality(Span::new(1, idx));
+            let (ord, after_ord) =
+                Ordinality::try_parse(before_calle).map_err(|_| not_ordinality)?;
 
             if !after_ord.is_empty() {
-                return Err(ParseError::LexemesBeforeCalleNotOrdinality);
+                return Err(ParseError::LexemesBeforeCalleNotOrdinality(Span::new(
+                    1, idx,
+                )));
             }
 
             if !ord.is_fem() {
@@ -54,19 +70,72 @@ impl StreetNounPhrase {
             Ok((Self::LaCalleOrd(ord), after_calle))
         }
     }
+
+    fn try_parse_named(lexemes: &[Lexeme]) -> Option<(StreetName, &[Lexeme])> {
+        let (_, rest) = DefiniteArticle::try_parse_la(lexemes)?;
+        StreetName::try_parse(rest).ok()
+    }
+}
+
+impl Display for StreetNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreetNounPhrase::LaCalle => write!(f, "{} {}", Lexeme::La, Lexeme::Calle),
+            // A masculine ordinality can only follow "calle".
+            StreetNounPhrase::LaCalleOrd(ord) if ord.is_masc() => {
+                write!(f, "{} {} {ord}", Lexeme::La, Lexeme::Calle)
+            }
+            StreetNounPhrase::LaCalleOrd(ord) => {
+                write!(f, "{} {ord} {}", Lexeme::La, Lexeme::Calle)
+            }
+            StreetNounPhrase::Named(name) => write!(f, "{} {name}", Lexeme::La),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("The noun phrase must contain \"calle\".")]
-    MissingCalle,
+    MissingCalle(Span),
 
     #[error("\"calle\" must be preceded by the article \"la\".")]
     MissingArticle,
 
-    #[error("The gender of the ordinality must be feminine to match \"calle\".")]
+    #[error(
+        "\"{0}\" is masculine, but \"calle\" is feminine. Use \"{}\" instead.",
+        .0.with_gender(Gender::Feminine)
+    )]
     OrdinalityGender(Ordinality),
 
     #[error("The word between \"la\" and \"calle\" must be an ordinality.")]
-    LexemesBeforeCalleNotOrdinality,
+    LexemesBeforeCalleNotOrdinality(Span),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingCalle(span) => *span,
+            ParseError::MissingArticle => Span::at(0),
+            // The ordinality is between "la" and "calle".
+            ParseError::OrdinalityGender(_) => Span::new(1, 3),
+            ParseError::LexemesBeforeCalleNotOrdinality(span) => *span,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreetNounPhrase;
+    use crate::lang::Lexeme;
+
+    #[test]
+    fn parse_named() {
+        let lexemes = Lexeme::parse_line("la Avenida Calle 85 a la derecha").unwrap();
+        let (np, rest) = StreetNounPhrase::try_parse(&lexemes).unwrap();
+        match np {
+            StreetNounPhrase::Named(name) => assert_eq!(name.to_string(), "Avenida Calle 85"),
+            _ => panic!("expected a named street"),
+        }
+        assert_eq!(rest, &lexemes[4..]);
+    }
 }
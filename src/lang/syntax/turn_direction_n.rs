@@ -1,6 +1,9 @@
 use crate::lang::Lexeme;
+use std::fmt::Display;
 use thiserror::Error;
 
+use super::Span;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TurnDirectionNoun {
     Izquierda,
@@ -19,6 +22,38 @@ impl TurnDirectionNoun {
     }
 }
 
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::Empty => Span::new(0, 0),
+            ParseError::Unrecognized(_) => Span::at(0),
+        }
+    }
+}
+
+impl TurnDirectionNoun {
+    /// Returns the noun for the other side.
+    pub fn opposite(&self) -> Self {
+        match self {
+            TurnDirectionNoun::Izquierda => TurnDirectionNoun::Derecha,
+            TurnDirectionNoun::Derecha => TurnDirectionNoun::Izquierda,
+        }
+    }
+
+    pub fn lexeme(&self) -> Lexeme {
+        match self {
+            TurnDirectionNoun::Izquierda => Lexeme::Izquierda,
+            TurnDirectionNoun::Derecha => Lexeme::Derecha,
+        }
+    }
+}
+
+impl Display for TurnDirectionNoun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lexeme())
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("There are no words to parse as a direction.")]
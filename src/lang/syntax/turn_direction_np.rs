@@ -1,5 +1,6 @@
-use super::{DefiniteArticle, TurnDirectionNoun};
+use super::{DefiniteArticle, Span, TurnDirectionNoun};
 use crate::lang::{syntax::parse, Lexeme};
+use std::fmt::Display;
 use thiserror::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,7 +9,7 @@ pub struct TurnDirectionNounPhrase(pub TurnDirectionNoun);
 impl TurnDirectionNounPhrase {
     pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
         let (before, dir, after) = parse::split_at_parsable(lexemes, TurnDirectionNoun::try_parse)
-            .ok_or(ParseError::MissingDirection)?;
+            .ok_or(ParseError::MissingDirection(Span::new(0, lexemes.len())))?;
 
         let ((), rest) = parse::or(
             before,
@@ -18,23 +19,42 @@ impl TurnDirectionNounPhrase {
         .ok_or(ParseError::MissingLaOrMano)?;
 
         if !rest.is_empty() {
-            Err(ParseError::LexemesBetweenLaOrManoAndDirection)
+            Err(ParseError::LexemesBetweenLaOrManoAndDirection(Span::new(
+                1,
+                before.len(),
+            )))
         } else {
             Ok((TurnDirectionNounPhrase(dir), after))
         }
     }
 }
 
+impl Display for TurnDirectionNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", Lexeme::La, self.0)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error(r#"The phrase must include a direction, either "izquierda" or "derecha"."#)]
-    MissingDirection,
+    MissingDirection(Span),
 
     #[error(r#"The direction must be immediately preceded by either "la" or "mano."#)]
     MissingLaOrMano,
 
     #[error(r#"The direction must be immediately preceded by either "la" or "mano."#)]
-    LexemesBetweenLaOrManoAndDirection,
+    LexemesBetweenLaOrManoAndDirection(Span),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingDirection(span) => *span,
+            ParseError::MissingLaOrMano => Span::at(0),
+            ParseError::LexemesBetweenLaOrManoAndDirection(span) => *span,
+        }
+    }
 }
 
 #[cfg(test)]
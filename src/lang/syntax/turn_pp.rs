@@ -1,7 +1,8 @@
 use crate::lang::Lexeme;
+use std::fmt::Display;
 use thiserror::Error;
 
-use super::{parse, TurnDirectionNounPhrase, TurnDirectionNounPhraseParseError};
+use super::{parse, Span, TurnDirectionNounPhrase, TurnDirectionNounPhraseParseError};
 
 /// Prepositional phrase describing a left or right turn.
 ///
@@ -19,16 +20,31 @@ impl LeftRightTurnPrepPhrase {
         let (_, rest) =
             parse::consume_lexeme(lexemes, Lexeme::A).ok_or(ParseError::MissingPreposition)?;
         let (np, rest) = TurnDirectionNounPhrase::try_parse(rest)
-            .map_err(ParseError::MissingDirectionNounPhrase)?;
+            .map_err(|e| ParseError::MissingDirectionNounPhrase(1, e))?;
         Ok((LeftRightTurnPrepPhrase(np), rest))
     }
 }
 
+impl Display for LeftRightTurnPrepPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", Lexeme::A, self.0)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error(r#"The preposition "a" must be in the prepositional phrase."#)]
     MissingPreposition,
 
-    #[error(r#"The preposition must contain a direction noun phrase: {0}"#)]
-    MissingDirectionNounPhrase(TurnDirectionNounPhraseParseError),
+    #[error(r#"The preposition must contain a direction noun phrase: {1}"#)]
+    MissingDirectionNounPhrase(usize, #[source] TurnDirectionNounPhraseParseError),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingPreposition => Span::at(0),
+            ParseError::MissingDirectionNounPhrase(offset, e) => e.span().offset(*offset),
+        }
+    }
 }
@@ -1,10 +1,11 @@
+use std::fmt::Display;
 use thiserror::Error;
 
-use super::{StreetNounPhrase, StreetNounPhraseParseError};
+use super::{Span, StreetNounPhrase, StreetNounPhraseParseError};
 use crate::lang::Lexeme;
 
 /// A noun phrase describing a place onto which one may turn.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TurnableNounPhrase {
     Street(StreetNounPhrase),
 }
@@ -18,8 +19,24 @@ impl TurnableNounPhrase {
     }
 }
 
+impl Display for TurnableNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TurnableNounPhrase::Street(s_np) => write!(f, "{s_np}"),
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("{0}")]
-    StreetNounPhrase(StreetNounPhraseParseError),
+    StreetNounPhrase(#[source] StreetNounPhraseParseError),
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::StreetNounPhrase(e) => e.span(),
+        }
+    }
 }
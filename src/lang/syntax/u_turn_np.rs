@@ -0,0 +1,59 @@
+use crate::lang::{syntax::parse, Lexeme};
+use std::fmt::Display;
+use thiserror::Error;
+
+use super::Span;
+
+/// A noun phrase describing a U-turn.
+///
+/// Examples:
+/// * "la vuelta"
+/// * "media vuelta"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UTurnNounPhrase {
+    LaVuelta,
+    MediaVuelta,
+}
+
+impl UTurnNounPhrase {
+    pub fn try_parse(lexemes: &[Lexeme]) -> Result<(Self, &[Lexeme]), ParseError> {
+        let (first, rest) = lexemes.split_first().ok_or(ParseError::MissingLaOrMedia)?;
+        let np = match first {
+            Lexeme::La => Ok(Self::LaVuelta),
+            Lexeme::Media => Ok(Self::MediaVuelta),
+            _ => Err(ParseError::MissingLaOrMedia),
+        }?;
+
+        let ((), rest) =
+            parse::consume_lexeme(rest, Lexeme::Vuelta).ok_or(ParseError::MissingVuelta)?;
+        Ok((np, rest))
+    }
+}
+
+impl Display for UTurnNounPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let article = match self {
+            UTurnNounPhrase::LaVuelta => Lexeme::La,
+            UTurnNounPhrase::MediaVuelta => Lexeme::Media,
+        };
+        write!(f, "{} {}", article, Lexeme::Vuelta)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error(r#"The phrase must contain "vuelta"."#)]
+    MissingVuelta,
+
+    #[error(r#""vuelta" must be immediately preceded by either "la" or "media"."#)]
+    MissingLaOrMedia,
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MissingVuelta => Span::at(1),
+            ParseError::MissingLaOrMedia => Span::at(0),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! This module splits a line of text into the words that are read as lexemes.
+
+use serde::Deserialize;
+use std::ops::Range;
+
+/// Punctuation that may surround a word and is ignored when reading it.
+const PUNCTUATION: [char; 7] = ['¿', '?', '¡', '!', '.', ',', ';'];
+
+/// How words that are missing accents are read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Accents {
+    /// A word that only differs from a lexeme by its accents is read as that
+    /// lexeme, so "continua" is read as "continúa".
+    Lenient,
+
+    /// A word that only differs from a lexeme by its accents is an orthography
+    /// error.
+    #[default]
+    Strict,
+}
+
+/// Returns the byte range of each word in the line. Words are separated by
+/// whitespace and punctuation, so the punctuation is never part of a word.
+pub fn tokenize(line: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut start = None;
+    for (i, c) in line.char_indices() {
+        let is_separator = c.is_whitespace() || PUNCTUATION.contains(&c);
+        match (is_separator, start) {
+            (true, Some(s)) => {
+                ranges.push(s..i);
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        ranges.push(s..line.len());
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Accents};
+    use crate::lang::{Lexeme, WordError};
+
+    #[test]
+    fn strip_punctuation_and_grade_accents() {
+        let line = "¡Continúa todo derecho!";
+        let words = tokenize(line)
+            .into_iter()
+            .map(|r| &line[r])
+            .collect::<Vec<_>>();
+        assert_eq!(words, ["Continúa", "todo", "derecho"]);
+
+        let line = "¿Gira a la derecha, y luego continua hasta el final de la calle?";
+        let lenient = Lexeme::parse_line_with(line, Accents::Lenient, &Lexeme::all()).unwrap();
+        assert_eq!(lenient[0], Lexeme::Gira);
+        assert_eq!(lenient[6], Lexeme::Continúa);
+
+        let strict = Lexeme::parse_line_with(line, Accents::Strict, &Lexeme::all()).unwrap_err();
+        assert!(matches!(
+            strict.0.as_slice(),
+            [WordError::Accents {
+                lexeme: Lexeme::Continúa,
+                ..
+            }]
+        ));
+    }
+}
@@ -0,0 +1,346 @@
+//! This module defines the level file format and the logic for loading a level
+//! into a playable `State`.
+//!
+//! A level is a TOML file. The road and building tables can either be embedded
+//! in the file or referenced by a path relative to the level file. The map's
+//! `width` and `height` are optional and default to the extent of its roads
+//! and buildings, in which case `check` warns that it cannot find roads and
+//! buildings that run off the map. The optional `words` limits the words that
+//! sentences may use; a numeral that counts blocks, as in "7 quadras", must be
+//! listed like any other word, but one that names a street need not be. The
+//! optional `register` ("tú", "usted" or "vos") requires every verb to use that
+//! form of address. The optional `accents` is either "strict", the default,
+//! which makes a word that is missing its accents an error, or "lenient", which
+//! reads the word as if it had them.
+//!
+//! Example:
+//! ```toml
+//! title = "Zona T"
+//! description = "Find your way to the hospital."
+//! goal = "el hospital"
+//! words = ["gira", "a", "la", "derecha"]
+//! register = "tú"
+//! accents = "lenient"
+//!
+//! [map]
+//! roads = { path = "zonat_25x16_roads.csv" }
+//! buildings = { csv = """
+//! origin_x,origin_y,dim_x,dim_y,name
+//! 12,2,2,2,el hospital
+//! """ }
+//!
+//! [start]
+//! x = 7
+//! y = 10
+//! facing = "south"
+//! ```
+
+use iter_tools::Itertools;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+use vek::Vec2;
+
+use crate::describe::describe;
+use crate::direction::CardinalDirection;
+use crate::lang::syntax::LandmarkNounPhrase;
+use crate::lang::{Accents, Directions, Lexeme, Register};
+use crate::map::{BuildingId, Cell, CsvTable, Map, ReadError, RoadGraph};
+use crate::pose::Pose;
+use crate::solve::{solve, Route};
+use crate::state::State;
+
+/// A level that is ready to be played.
+#[derive(Debug, Clone)]
+pub struct Level {
+    pub title: String,
+    pub description: Option<String>,
+    map: Map,
+
+    /// The roads of the map, built once since every sentence is read against
+    /// them.
+    graph: RoadGraph,
+
+    start: Pose,
+    goal: BuildingId,
+    word_bank: Option<Vec<Lexeme>>,
+    register: Option<Register>,
+    accents: Accents,
+}
+
+impl Level {
+    /// Reads the level file at the given path.
+    pub fn load(path: &Path) -> Result<Self, LevelError> {
+        let toml = std::fs::read_to_string(path).map_err(|source| LevelError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let dir = path.parent().unwrap_or(Path::new("."));
+        Self::from_toml(&toml, dir)
+    }
+
+    /// Parses a level from TOML. Table paths are resolved relative to `dir`.
+    pub fn from_toml(toml: &str, dir: &Path) -> Result<Self, LevelError> {
+        let file: LevelFile = toml::from_str(toml)?;
+
+        let (roads, roads_file) = file.map.roads.read(dir)?;
+        let (buildings, buildings_file) = file.map.buildings.read(dir)?;
+        let map = Map::from_csvs(
+            file.map.width,
+            file.map.height,
+            roads.as_bytes(),
+            buildings.as_bytes(),
+        )
+        .map_err(|source| LevelError::Map {
+            file: match source.table() {
+                CsvTable::Roads => roads_file,
+                CsvTable::Buildings => buildings_file,
+            },
+            source,
+        })?;
+
+        let start = Pose::new(file.start.x, file.start.y, file.start.facing);
+        check_start(&map, &start)?;
+        let goal = find_goal(&map, &file.goal)?;
+
+        let word_bank = file.words.as_deref().map(parse_words).transpose()?;
+
+        Ok(Self {
+            title: file.title,
+            description: file.description,
+            graph: RoadGraph::new(&map),
+            map,
+            start,
+            goal,
+            word_bank,
+            register: file.register,
+            accents: file.accents.unwrap_or_default(),
+        })
+    }
+
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+
+    /// Replaces the pose at which the player starts the level.
+    pub fn set_start(&mut self, start: Pose) -> Result<(), LevelError> {
+        check_start(&self.map, &start)?;
+        self.start = start;
+        Ok(())
+    }
+
+    /// Replaces the goal with the building of the given name.
+    pub fn set_goal(&mut self, name: &str) -> Result<(), LevelError> {
+        self.goal = find_goal(&self.map, name)?;
+        Ok(())
+    }
+
+    /// Returns the names of the buildings that no sentence can refer to.
+    ///
+    /// Sentences refer to buildings with landmark noun phrases, whose nouns
+    /// come from a closed vocabulary, so a building named anything other than
+    /// one of them with its article, such as "el hospital", cannot be reached
+    /// by name. `check` warns about these buildings.
+    pub fn unnamable_buildings(&self) -> Vec<&str> {
+        self.map
+            .buildings()
+            .iter()
+            .filter_map(|b| b.name())
+            .filter(|name| LandmarkNounPhrase::from_name(name).is_none())
+            .collect()
+    }
+
+    /// Finds the cheapest route from the start to the goal, if there is one.
+    pub fn solve(&self) -> Option<Route> {
+        solve(&self.map, &self.start, self.goal)
+    }
+
+    /// Describes the route from the start in Spanish, if it can be put into
+    /// words.
+    pub fn describe(&self, route: &Route) -> Option<Directions> {
+        describe(&self.map, &self.graph, &self.start, &route.cmds, self.goal)
+    }
+
+    /// Returns the game state at the start of the level.
+    pub fn into_state(self) -> State {
+        State::new(
+            self.map,
+            self.graph,
+            self.word_bank,
+            self.register,
+            self.accents,
+            self.start,
+            self.goal,
+        )
+    }
+}
+
+fn check_start(map: &Map, start: &Pose) -> Result<(), LevelError> {
+    if map.get(start.position()).is_some_and(Cell::is_road) {
+        Ok(())
+    } else {
+        let Vec2 { x, y } = start.position();
+        Err(LevelError::StartNotOnRoad(x, y))
+    }
+}
+
+fn find_goal(map: &Map, name: &str) -> Result<BuildingId, LevelError> {
+    map.buildings()
+        .iter()
+        .find(|b| {
+            b.name()
+                .is_some_and(|b_name| b_name.trim().eq_ignore_ascii_case(name.trim()))
+        })
+        .map(|b| b.id())
+        .ok_or_else(|| LevelError::GoalNotFound(name.to_string()))
+}
+
+fn parse_words(words: &[String]) -> Result<Vec<Lexeme>, LevelError> {
+    let (lexemes, unknowns): (Vec<_>, Vec<_>) = words
+        .iter()
+        .map(|w| Lexeme::from_str(w).map_err(|_| w.clone()))
+        .partition_result();
+
+    if unknowns.is_empty() {
+        Ok(lexemes)
+    } else {
+        Err(LevelError::UnknownWords(unknowns))
+    }
+}
+
+/// The contents of a level file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LevelFile {
+    title: String,
+    description: Option<String>,
+    map: MapSection,
+    start: StartSection,
+    goal: String,
+    words: Option<Vec<String>>,
+    register: Option<Register>,
+    accents: Option<Accents>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MapSection {
+    width: Option<usize>,
+    height: Option<usize>,
+    roads: Table,
+    buildings: Table,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StartSection {
+    x: usize,
+    y: usize,
+    facing: CardinalDirection,
+}
+
+/// A CSV table that is either embedded in the level file or stored in its own
+/// file.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Table {
+    Path { path: PathBuf },
+    Inline { csv: String },
+}
+
+impl Table {
+    /// Returns the contents of the table and a description of where it came
+    /// from for use in error messages.
+    fn read(self, dir: &Path) -> Result<(String, String), LevelError> {
+        match self {
+            Table::Path { path } => {
+                let path = dir.join(path);
+                let csv = std::fs::read_to_string(&path).map_err(|source| LevelError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+                Ok((csv, path.display().to_string()))
+            }
+            Table::Inline { csv } => Ok((csv, "the level file".to_string())),
+        }
+    }
+}
+
+/// An error that occurs trying to load a level.
+#[derive(Debug, Error)]
+pub enum LevelError {
+    #[error("failed to read {}", .path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("invalid level file")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("invalid map data in {file}")]
+    Map { file: String, source: ReadError },
+
+    #[error("the start position ({0}, {1}) is not on a road")]
+    StartNotOnRoad(usize, usize),
+
+    #[error("there is no building named \"{0}\"")]
+    GoalNotFound(String),
+
+    #[error("the word bank contains unrecognized words: {0:?}")]
+    UnknownWords(Vec<String>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Level, LevelError};
+    use crate::lang::Register;
+    use crate::map::fixture::{BUILDINGS_HEADER, ROADS_HEADER};
+    use std::path::Path;
+
+    /// Returns a level whose map is embedded in the file.
+    fn level() -> String {
+        format!(
+            r#"
+title = "Test"
+goal = "la casa"
+words = ["gira", "a", "la", "derecha"]
+
+[map]
+roads = {{ csv = """
+{ROADS_HEADER}
+ns,1,0,3,0,Calle 1
+""" }}
+buildings = {{ csv = """
+{BUILDINGS_HEADER}
+0,0,1,1,la casa
+""" }}
+
+[start]
+x = 1
+y = 0
+facing = "north"
+"#
+        )
+    }
+
+    #[test]
+    fn load_inline_level() {
+        let toml = level();
+        let level = Level::from_toml(&toml, Path::new(".")).unwrap();
+        assert_eq!(level.title, "Test");
+        assert_eq!(level.word_bank.as_ref().map(Vec::len), Some(4));
+
+        let no_goal = toml.replace(r#"goal = "la casa""#, r#"goal = "el hotel""#);
+        assert!(matches!(
+            Level::from_toml(&no_goal, Path::new(".")),
+            Err(LevelError::GoalNotFound(_))
+        ));
+
+        // Top-level keys must come before the first table.
+        let usted = format!("register = \"usted\"\n{toml}");
+        let level = Level::from_toml(&usted, Path::new(".")).unwrap();
+        assert_eq!(level.register, Some(Register::Usted));
+    }
+}
@@ -1,44 +1,186 @@
 mod cmd;
+mod describe;
 mod direction;
 mod grid;
 mod lang;
+mod level;
 mod map;
 mod pose;
+mod score;
+mod solve;
 mod state;
 mod ui;
 
+use clap::{Args, Parser, Subcommand};
 use direction::{CardinalDirection, TurnDirection};
 use iter_tools::Itertools;
-use lang::Lexeme;
+use level::{Level, LevelError};
 use map::Map;
 use pose::Pose;
-use state::State;
+use std::error::Error;
 use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-use crate::map::BuildingId;
+/// A game for practicing giving directions in Spanish.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Plays a level.
+    Play {
+        /// Path to the level file.
+        level: PathBuf,
+
+        #[command(flatten)]
+        overrides: Overrides,
+    },
+
+    /// Checks that a level file is valid.
+    Check {
+        /// Path to the level file.
+        level: PathBuf,
+
+        #[command(flatten)]
+        overrides: Overrides,
+    },
+
+    /// Prints the map of a level.
+    Render {
+        /// Path to the level file.
+        level: PathBuf,
+
+        #[command(flatten)]
+        overrides: Overrides,
+    },
+}
 
-fn main() {
-    let roads_csv = std::fs::File::open("./example_maps/zonat_25x16_roads.csv").unwrap();
-    let buildings_csv = std::fs::File::open("./example_maps/zonat_25x16_buildings.csv").unwrap();
-    let map = Map::from_csvs(25, 16, roads_csv, buildings_csv).unwrap();
-    let init_pose = Pose::new(7, 10, CardinalDirection::South);
-    let word_bank = Lexeme::all();
-    let goal = BuildingId::new(2); // la hospital
-    let mut state = State::new(map, word_bank, init_pose, goal);
+/// Settings that replace the ones in the level file.
+#[derive(Args)]
+struct Overrides {
+    /// The start pose as "x,y,facing", e.g. "7,10,south".
+    #[arg(long, value_parser = parse_pose)]
+    start: Option<Pose>,
+
+    /// The name of the goal building, e.g. "el hospital".
+    #[arg(long)]
+    goal: Option<String>,
+}
+
+/// The player stopped before reaching the goal.
+const EXIT_NOT_WON: u8 = 3;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (path, overrides) = match &cli.command {
+        Command::Play { level, overrides }
+        | Command::Check { level, overrides }
+        | Command::Render { level, overrides } => (level, overrides),
+    };
+
+    let level = match load_level(path, overrides) {
+        Ok(level) => level,
+        Err(e) => {
+            report(&e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Command::Play { .. } => play(level),
+        Command::Check { .. } => {
+            let errors = level.map().validate();
+            if !errors.is_empty() {
+                errors.iter().for_each(|e| eprintln!("error: {e}"));
+                return ExitCode::FAILURE;
+            }
+            if level.map().size_inferred() {
+                eprintln!(
+                    "warning: the map does not declare its width and height, \
+                     so roads and buildings that run off it cannot be found"
+                );
+            }
+            for name in level.unnamable_buildings() {
+                eprintln!("warning: no sentence can refer to the building \"{name}\"");
+            }
+            match level.solve() {
+                Some(route) => {
+                    println!("{}: ok", level.title);
+                    println!(
+                        "Best route ({} cells, {} turns): {:?}",
+                        route.distance(),
+                        route.turns(),
+                        route.cmds
+                    );
+                    if let Some(directions) = level.describe(&route) {
+                        println!("Directions: {}", describe::render(&directions));
+                    }
+                    ExitCode::SUCCESS
+                }
+                None => {
+                    eprintln!("error: the goal cannot be reached from the start");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Render { .. } => {
+            level.into_state().render_map();
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn load_level(path: &Path, overrides: &Overrides) -> Result<Level, LevelError> {
+    let mut level = Level::load(path)?;
+    if let Some(start) = overrides.start {
+        level.set_start(start)?;
+    }
+    if let Some(goal) = &overrides.goal {
+        level.set_goal(goal)?;
+    }
+    Ok(level)
+}
+
+/// Runs the game loop, reading commands from stdin until the player reaches
+/// the goal or stops.
+fn play(level: Level) -> ExitCode {
+    println!("{}", level.title);
+    if let Some(description) = &level.description {
+        println!("{description}");
+    }
+    let mut state = level.into_state();
 
     state.render_map();
     print!("{}", state);
 
     let stdin = io::stdin();
     for line in stdin.lock().lines() {
-        let line = line.unwrap();
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                report(&e);
+                return ExitCode::FAILURE;
+            }
+        };
 
         if line == *"para" {
-            return;
+            return ExitCode::from(EXIT_NOT_WON);
         } else if line == *"el mapa" {
             state.render_map();
         } else if line == *"ve" {
-            let _ = state.apply_sentence_cmds();
+            if state.apply_sentence_cmds().is_ok() && state.is_winning() {
+                state.render_map();
+                println!("¡Llegaste!");
+                if let Some(score) = state.score() {
+                    print!("{score}");
+                }
+                return ExitCode::SUCCESS;
+            }
         } else if line == *"las palabras" {
             let mut words = state
                 .word_bank()
@@ -53,4 +195,38 @@ fn main() {
 
         print!("{}", state);
     }
+
+    ExitCode::from(EXIT_NOT_WON)
+}
+
+/// Parses a pose written as "x,y,facing".
+fn parse_pose(s: &str) -> Result<Pose, String> {
+    let parts = s.split(',').map(str::trim).collect_vec();
+    let [x, y, facing] = parts[..] else {
+        return Err(format!("expected \"x,y,facing\" but found \"{s}\""));
+    };
+    let x = x
+        .parse()
+        .map_err(|_| format!("invalid x coordinate \"{x}\""))?;
+    let y = y
+        .parse()
+        .map_err(|_| format!("invalid y coordinate \"{y}\""))?;
+    let facing = match facing.to_lowercase().as_str() {
+        "north" => CardinalDirection::North,
+        "east" => CardinalDirection::East,
+        "south" => CardinalDirection::South,
+        "west" => CardinalDirection::West,
+        _ => return Err(format!("invalid direction \"{facing}\"")),
+    };
+    Ok(Pose::new(x, y, facing))
+}
+
+/// Prints the error and its chain of causes to stderr.
+fn report(err: &dyn Error) {
+    eprintln!("error: {err}");
+    let mut source = err.source();
+    while let Some(cause) = source {
+        eprintln!("  caused by: {cause}");
+        source = cause.source();
+    }
 }
@@ -23,6 +23,9 @@ pub struct Building {
 
     /// Name of the building.
     name: Option<String>,
+
+    /// Line of the CSV from which the building was read, if any.
+    line: Option<usize>,
 }
 
 impl Building {
@@ -32,12 +35,14 @@ impl Building {
         origin: Vec2<usize>,
         dim: Vec2<usize>,
         name: Option<String>,
+        line: Option<usize>,
     ) -> Self {
         Self {
             id,
             origin,
             dim,
             name,
+            line,
         }
     }
 
@@ -46,6 +51,21 @@ impl Building {
         self.id
     }
 
+    /// Returns the name of the building.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the width and height of the building.
+    pub fn dim(&self) -> Vec2<usize> {
+        self.dim
+    }
+
+    /// Returns the line of the CSV from which the building was read, if any.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
     /// Returns the grid coordinates containing the minimum corner of the
     /// building.
     pub fn min(&self) -> Vec2<usize> {
@@ -55,7 +75,7 @@ impl Building {
     /// Returns the grid coordinates containing the maximum corner of the
     /// building.
     pub fn max(&self) -> Vec2<usize> {
-        let delta = Vec2::new(self.dim.x - 1, self.dim.y - 1);
+        let delta = Vec2::new(self.dim.x.saturating_sub(1), self.dim.y.saturating_sub(1));
         self.origin + delta
     }
 }
@@ -1,7 +1,8 @@
 use super::{BuildingId, RoadId};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum Cell {
+    #[default]
     Empty,
     Road(RoadId),
     Building(BuildingId),
@@ -12,9 +13,3 @@ impl Cell {
         matches!(self, Cell::Road(_))
     }
 }
-
-impl Default for Cell {
-    fn default() -> Self {
-        Cell::Empty
-    }
-}
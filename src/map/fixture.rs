@@ -0,0 +1,35 @@
+//! Maps for tests, built from the rows of roads and buildings CSVs.
+
+use super::Map;
+
+/// The header of a roads CSV.
+pub const ROADS_HEADER: &str = "orientation,origin_x,origin_y,length,rank,name";
+
+/// The header of a buildings CSV.
+pub const BUILDINGS_HEADER: &str = "origin_x,origin_y,dim_x,dim_y,name";
+
+/// Builds a map from CSV rows given without their headers, sizing it to fit
+/// its roads and buildings.
+pub fn map(roads: &[&str], buildings: &[&str]) -> Map {
+    sized_map(None, None, roads, buildings)
+}
+
+/// Builds a map of the given size from CSV rows given without their headers.
+pub fn sized_map(
+    width: Option<usize>,
+    height: Option<usize>,
+    roads: &[&str],
+    buildings: &[&str],
+) -> Map {
+    let csv = |header: &str, rows: &[&str]| {
+        rows.iter()
+            .fold(format!("{header}\n"), |csv, row| csv + row + "\n")
+    };
+    Map::from_csvs(
+        width,
+        height,
+        csv(ROADS_HEADER, roads).as_bytes(),
+        csv(BUILDINGS_HEADER, buildings).as_bytes(),
+    )
+    .unwrap()
+}
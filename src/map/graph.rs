@@ -0,0 +1,406 @@
+use super::{BuildingId, Cell, Map, RoadId, RoadOrientation};
+use crate::direction::CardinalDirection;
+use crate::pose::Pose;
+use std::collections::HashMap;
+use vek::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdgeId(usize);
+
+/// A building beside a road cell, on the given side of it.
+type Beside = (CardinalDirection, BuildingId);
+
+/// A road cell where the player can do something other than continue straight
+/// ahead: an intersection, a corner or a dead end.
+#[derive(Debug, Clone)]
+pub struct Node {
+    id: NodeId,
+    position: Vec2<usize>,
+
+    /// The edge leaving the node in each cardinal direction, indexed by
+    /// `exit_index`.
+    exits: [Option<EdgeId>; 4],
+
+    /// The buildings beside the node.
+    beside: Vec<Beside>,
+}
+
+impl Node {
+    /// Returns the edge leaving the node in the given direction.
+    pub fn exit(&self, dir: CardinalDirection) -> Option<EdgeId> {
+        self.exits[exit_index(dir)]
+    }
+
+    /// Returns `true` if a road leaves the node in the given direction.
+    pub fn has_exit(&self, dir: CardinalDirection) -> bool {
+        self.exit(dir).is_some()
+    }
+}
+
+/// A straight stretch of road between two nodes.
+#[derive(Debug, Clone)]
+pub struct Edge {
+    /// The node at which the edge starts.
+    from: NodeId,
+
+    /// The node at which the edge ends.
+    to: NodeId,
+
+    /// The direction of travel from `from` to `to`.
+    direction: CardinalDirection,
+
+    /// Number of steps from one end of the edge to the other.
+    length: usize,
+
+    /// The name of the road that the edge runs along. This is `None` if the
+    /// road is unnamed or the edge only joins two roads that happen to be side
+    /// by side.
+    name: Option<String>,
+
+    /// The buildings beside the cells between the two nodes, along with the
+    /// number of steps from `from` to each cell.
+    beside: Vec<(usize, CardinalDirection, BuildingId)>,
+}
+
+impl Edge {
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the node at the other end of the edge from the given node.
+    pub fn other_end(&self, node: NodeId) -> NodeId {
+        if node == self.from {
+            self.to
+        } else {
+            self.from
+        }
+    }
+}
+
+/// Where a road cell sits in the graph.
+#[derive(Debug, Clone, Copy)]
+enum Place {
+    Node(NodeId),
+
+    /// Inside an edge, the given number of steps from its `from` node.
+    Edge(EdgeId, usize),
+}
+
+/// The roads of a map as a graph whose nodes are intersections, corners and
+/// dead ends, and whose edges are the straight stretches of road between them.
+#[derive(Debug, Clone)]
+pub struct RoadGraph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    places: HashMap<Vec2<usize>, Place>,
+}
+
+impl RoadGraph {
+    pub fn new(map: &Map) -> Self {
+        let mut graph = Self {
+            nodes: vec![],
+            edges: vec![],
+            places: HashMap::new(),
+        };
+
+        let size = map.size();
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let idx = Vec2::new(x, y);
+                if is_node(map, idx) {
+                    let id = NodeId(graph.nodes.len());
+                    graph.nodes.push(Node {
+                        id,
+                        position: idx,
+                        exits: [None; 4],
+                        beside: buildings_beside(map, idx),
+                    });
+                    graph.places.insert(idx, Place::Node(id));
+                }
+            }
+        }
+
+        for i in 0..graph.nodes.len() {
+            for dir in CardinalDirection::all() {
+                if graph.nodes[i].exit(dir).is_none() {
+                    graph.add_edge(map, NodeId(i), dir);
+                }
+            }
+        }
+
+        graph
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn edge(&self, id: EdgeId) -> &Edge {
+        &self.edges[id.0]
+    }
+
+    /// Returns the node at the given coordinates, if any.
+    pub fn node_at(&self, idx: Vec2<usize>) -> Option<&Node> {
+        match self.places.get(&idx)? {
+            Place::Node(id) => Some(self.node(*id)),
+            Place::Edge(_, _) => None,
+        }
+    }
+
+    /// Returns the edges that the road cell at the given coordinates belongs
+    /// to: every edge leaving it if it is a node, or else the one edge running
+    /// through it.
+    pub fn edges_at(&self, idx: Vec2<usize>) -> Vec<&Edge> {
+        match self.places.get(&idx) {
+            Some(Place::Node(id)) => self
+                .node(*id)
+                .exits
+                .iter()
+                .flatten()
+                .map(|id| self.edge(*id))
+                .collect(),
+            Some(Place::Edge(id, _)) => vec![self.edge(*id)],
+            None => vec![],
+        }
+    }
+
+    /// Returns each node that the player passes walking straight ahead until
+    /// the road ends, along with its distance from the player. The node the
+    /// player is standing on, if any, is not included.
+    pub fn nodes_ahead(&self, pose: &Pose) -> Vec<(usize, &Node)> {
+        let dir = pose.direction();
+        let (mut dist, mut node) = match self.places.get(&pose.position()) {
+            Some(Place::Node(id)) => (0, self.node(*id)),
+            Some(Place::Edge(id, offset)) => {
+                let edge = self.edge(*id);
+                if dir == edge.direction {
+                    (edge.length - offset, self.node(edge.to))
+                } else if dir == edge.direction.opposite() {
+                    (*offset, self.node(edge.from))
+                } else {
+                    return vec![];
+                }
+            }
+            None => return vec![],
+        };
+
+        let mut nodes = vec![];
+        if dist > 0 {
+            nodes.push((dist, node));
+        }
+        while let Some(edge) = node.exit(dir).map(|id| self.edge(id)) {
+            dist += edge.length();
+            node = self.node(edge.other_end(node.id));
+            nodes.push((dist, node));
+        }
+        nodes
+    }
+
+    /// Returns each building beside the road that the player passes walking
+    /// straight ahead until the road ends, along with the side of the road it
+    /// is on and its distance from the player. The buildings beside the
+    /// player's own cell are at a distance of zero.
+    pub fn buildings_ahead(&self, pose: &Pose) -> Vec<(usize, CardinalDirection, BuildingId)> {
+        let dir = pose.direction();
+        let mut buildings = vec![];
+        let (mut dist, mut node) = match self.places.get(&pose.position()) {
+            Some(Place::Node(id)) => (0, self.node(*id)),
+            Some(Place::Edge(id, offset)) => {
+                let edge = self.edge(*id);
+                let offset = *offset;
+                let beside = edge.beside.iter().copied();
+                if dir == edge.direction {
+                    buildings.extend(
+                        beside
+                            .filter(|(o, _, _)| *o >= offset)
+                            .map(|(o, side, b)| (o - offset, side, b)),
+                    );
+                    (edge.length - offset, self.node(edge.to))
+                } else if dir == edge.direction.opposite() {
+                    buildings.extend(
+                        beside
+                            .filter(|(o, _, _)| *o <= offset)
+                            .map(|(o, side, b)| (offset - o, side, b)),
+                    );
+                    (offset, self.node(edge.from))
+                } else {
+                    buildings.extend(
+                        beside
+                            .filter(|(o, _, _)| *o == offset)
+                            .map(|(_, side, b)| (0, side, b)),
+                    );
+                    return buildings;
+                }
+            }
+            None => return buildings,
+        };
+
+        loop {
+            buildings.extend(node.beside.iter().map(|(side, b)| (dist, *side, *b)));
+            let Some(edge) = node.exit(dir).map(|id| self.edge(id)) else {
+                break;
+            };
+            buildings.extend(edge.beside.iter().map(|(o, side, b)| {
+                let steps = if edge.from == node.id {
+                    *o
+                } else {
+                    edge.length - o
+                };
+                (dist + steps, *side, *b)
+            }));
+            dist += edge.length;
+            node = self.node(edge.other_end(node.id));
+        }
+        buildings.sort_by_key(|(dist, _, _)| *dist);
+        buildings
+    }
+
+    /// Walks from the node in the given direction until reaching another node
+    /// and adds the edge between them, if there is a road in that direction.
+    fn add_edge(&mut self, map: &Map, from: NodeId, dir: CardinalDirection) {
+        let start = self.node(from).position;
+        let id = EdgeId(self.edges.len());
+        let mut interior = vec![];
+        let mut idx = start;
+        let to = loop {
+            let Some(next) = map.neighbor_index(idx, dir).filter(|i| is_road(map, *i)) else {
+                return;
+            };
+            idx = next;
+            match self.places.get(&idx) {
+                Some(Place::Node(to)) => break *to,
+                _ => interior.push(idx),
+            }
+        };
+
+        for (i, cell) in interior.iter().enumerate() {
+            self.places.insert(*cell, Place::Edge(id, i + 1));
+        }
+
+        let name = road_between(map, start, idx, dir)
+            .and_then(|r| map.road(r))
+            .and_then(|r| r.name())
+            .map(str::to_string);
+        let beside = interior
+            .iter()
+            .enumerate()
+            .flat_map(|(i, cell)| {
+                buildings_beside(map, *cell)
+                    .into_iter()
+                    .map(move |(side, b)| (i + 1, side, b))
+            })
+            .collect();
+        self.edges.push(Edge {
+            from,
+            to,
+            direction: dir,
+            length: interior.len() + 1,
+            name,
+            beside,
+        });
+        self.nodes[from.0].exits[exit_index(dir)] = Some(id);
+        self.nodes[to.0].exits[exit_index(dir.opposite())] = Some(id);
+    }
+}
+
+fn exit_index(dir: CardinalDirection) -> usize {
+    match dir {
+        CardinalDirection::North => 0,
+        CardinalDirection::East => 1,
+        CardinalDirection::South => 2,
+        CardinalDirection::West => 3,
+    }
+}
+
+/// Returns the buildings next to the cell, along with the side of the cell
+/// that each one is on.
+fn buildings_beside(map: &Map, idx: Vec2<usize>) -> Vec<Beside> {
+    CardinalDirection::all()
+        .into_iter()
+        .filter_map(|dir| match map.get_neighbor(idx, dir) {
+            Some(Cell::Building(id)) => Some((dir, *id)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_road(map: &Map, idx: Vec2<usize>) -> bool {
+    map.get(idx).is_some_and(|c| c.is_road())
+}
+
+/// Returns `true` if the road cell is anything other than a stretch of road
+/// that continues straight through it.
+fn is_node(map: &Map, idx: Vec2<usize>) -> bool {
+    if !is_road(map, idx) {
+        return false;
+    }
+    let exits = CardinalDirection::all().map(|dir| {
+        map.neighbor_index(idx, dir)
+            .is_some_and(|i| is_road(map, i))
+    });
+    !matches!(
+        exits,
+        [true, false, true, false] | [false, true, false, true]
+    )
+}
+
+/// Returns the most major road that runs through both cells in the given
+/// direction.
+fn road_between(
+    map: &Map,
+    a: Vec2<usize>,
+    b: Vec2<usize>,
+    dir: CardinalDirection,
+) -> Option<RoadId> {
+    let orientation = match dir {
+        CardinalDirection::North | CardinalDirection::South => RoadOrientation::NorthSouth,
+        CardinalDirection::East | CardinalDirection::West => RoadOrientation::EastWest,
+    };
+    map.roads
+        .iter()
+        .filter(|r| r.orientation() == orientation && r.contains(a) && r.contains(b))
+        .min_by_key(|r| r.rank())
+        .map(|r| r.id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoadGraph;
+    use crate::direction::CardinalDirection;
+    use crate::map::fixture;
+    use crate::pose::Pose;
+
+    #[test]
+    fn build_graph() {
+        // A north-south road crossed by an east-west road, with a dead end at
+        // each of the four ends.
+        let map = fixture::map(&["ns,2,0,7,1,Carrera 1", "ew,0,4,5,0,Calle 1"], &[]);
+        let graph = RoadGraph::new(&map);
+
+        assert_eq!(graph.nodes.len(), 5);
+        assert_eq!(graph.edges.len(), 4);
+
+        let crossing = graph.node_at(vek::Vec2::new(2, 4)).unwrap();
+        let south = graph.edge(crossing.exit(CardinalDirection::South).unwrap());
+        assert_eq!(south.length(), 4);
+        assert_eq!(south.name(), Some("Carrera 1"));
+
+        let pose = Pose::new(2, 1, CardinalDirection::North);
+        let ahead = graph
+            .nodes_ahead(&pose)
+            .into_iter()
+            .map(|(dist, node)| (dist, node.position))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ahead,
+            vec![(3, vek::Vec2::new(2, 4)), (5, vek::Vec2::new(2, 6))]
+        );
+    }
+}
@@ -1,16 +1,21 @@
 mod building;
 mod cell;
 mod display;
+#[cfg(test)]
+pub mod fixture;
+mod graph;
 mod read;
 mod road;
+mod validate;
 
 pub use building::{Building, BuildingId};
 pub use cell::Cell;
+pub use graph::{Edge, RoadGraph};
 use iter_tools::Itertools;
+pub use read::{CsvTable, ReadError};
 
 use crate::direction::CardinalDirection;
 use crate::grid::{Grid, Neighbors};
-use read::ReadError;
 use road::{Road, RoadId, RoadOrientation};
 use std::io;
 use vek::Vec2;
@@ -20,6 +25,10 @@ pub struct Map {
     grid: Grid<Cell>,
     roads: Vec<Road>,
     buildings: Vec<Building>,
+
+    /// Whether the width or height was inferred from the extent of the roads
+    /// and buildings rather than declared.
+    size_inferred: bool,
 }
 
 impl Map {
@@ -28,6 +37,7 @@ impl Map {
             grid: Grid::new(dim),
             roads: vec![],
             buildings: vec![],
+            size_inferred: false,
         }
     }
 
@@ -35,6 +45,13 @@ impl Map {
         self.grid.size()
     }
 
+    /// Returns whether the width or height was inferred from the extent of the
+    /// roads and buildings, in which case none of them can run off the map in
+    /// that direction.
+    pub fn size_inferred(&self) -> bool {
+        self.size_inferred
+    }
+
     pub fn grid(&self) -> &Grid<Cell> {
         &self.grid
     }
@@ -51,10 +68,38 @@ impl Map {
         self.grid.get_neighbor(idx, dir)
     }
 
-    /// Builds the game map from the given CSVs.
+    /// Returns the coordinates of the cell next to the given cell in the given
+    /// direction, if that cell is on the map.
+    pub fn neighbor_index(&self, idx: Vec2<usize>, dir: CardinalDirection) -> Option<Vec2<usize>> {
+        self.grid.neighbor_index(idx, dir)
+    }
+
+    pub fn buildings(&self) -> &[Building] {
+        &self.buildings
+    }
+
+    pub fn building(&self, id: BuildingId) -> Option<&Building> {
+        self.buildings.iter().find(|b| b.id() == id)
+    }
+
+    pub fn road(&self, id: RoadId) -> Option<&Road> {
+        self.roads.iter().find(|r| r.id() == id)
+    }
+
+    /// Returns `true` if the cell is next to the given building, including
+    /// diagonally.
+    pub fn is_beside(&self, idx: Vec2<usize>, building: BuildingId) -> bool {
+        self.get_neighbors(idx)
+            .into_vec()
+            .into_iter()
+            .any(|c| *c == Cell::Building(building))
+    }
+
+    /// Builds the game map from the given CSVs. The width and height default to
+    /// the smallest size that fits every road and building.
     pub fn from_csvs<R1, R2>(
-        width: usize,
-        height: usize,
+        width: Option<usize>,
+        height: Option<usize>,
         road_csv_reader: R1,
         building_csv_reader: R2,
     ) -> Result<Map, ReadError>
@@ -72,9 +117,10 @@ impl Map {
         orientation: RoadOrientation,
         rank: u8,
         name: Option<String>,
+        line: Option<usize>,
     ) {
         let id = RoadId::new(self.roads.len());
-        let road = Road::new(id, origin, length, orientation, rank, name);
+        let road = Road::new(id, origin, length, orientation, rank, name, line);
 
         for idx in self.road_indices(&road) {
             if let Some(cell) = self.grid.get_mut(idx) {
@@ -85,9 +131,15 @@ impl Map {
         self.roads.push(road);
     }
 
-    pub fn add_building(&mut self, origin: Vec2<usize>, dim: Vec2<usize>, name: Option<String>) {
+    pub fn add_building(
+        &mut self,
+        origin: Vec2<usize>,
+        dim: Vec2<usize>,
+        name: Option<String>,
+        line: Option<usize>,
+    ) {
         let id = BuildingId::new(self.buildings.len());
-        let building = Building::new(id, origin, dim, name);
+        let building = Building::new(id, origin, dim, name, line);
 
         for idx in self.building_indices(&building) {
             if let Some(cell) = self.grid.get_mut(idx) {
@@ -99,6 +151,9 @@ impl Map {
     }
 
     fn road_indices(&self, road: &Road) -> Vec<Vec2<usize>> {
+        if road.length() == 0 {
+            return vec![];
+        }
         match road.orientation() {
             RoadOrientation::NorthSouth => (road.origin().y..=road.terminus().y)
                 .map(|y| Vec2::new(road.origin().x, y))
@@ -110,6 +165,9 @@ impl Map {
     }
 
     fn building_indices(&self, building: &Building) -> Vec<Vec2<usize>> {
+        if building.dim().x == 0 || building.dim().y == 0 {
+            return vec![];
+        }
         let min = building.min();
         let max = building.max();
 
@@ -1,12 +1,16 @@
 use super::{Map, RoadOrientation};
 use csv::StringRecord;
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::io;
+use std::num::ParseIntError;
+use std::str::FromStr;
 use thiserror::Error;
 use vek::Vec2;
 
 pub fn from_csvs<R1, R2>(
-    width: usize,
-    height: usize,
+    width: Option<usize>,
+    height: Option<usize>,
     road_csv_reader: R1,
     building_csv_reader: R2,
 ) -> Result<Map, ReadError>
@@ -14,16 +18,25 @@ where
     R1: io::Read,
     R2: io::Read,
 {
-    let mut map = Map::new(Vec2::new(width, height));
+    let mut row_roads = read_rows(road_csv_reader, CsvTable::Roads, parse_road_row)?;
+    let row_buildings = read_rows(building_csv_reader, CsvTable::Buildings, parse_building_row)?;
 
-    let mut reader = csv::Reader::from_reader(road_csv_reader);
-    let mut row_roads = reader
-        .records()
-        .map(|rslt| {
-            let record = rslt.map_err(ReadError::RoadCsv)?;
-            parse_road_row(&record).ok_or(ReadError::ParseRoad(record))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+    let extent = row_roads
+        .iter()
+        .filter(|row| row.length > 0)
+        .map(|row| row.extent)
+        .chain(
+            row_buildings
+                .iter()
+                .filter(|row| row.dim.x > 0 && row.dim.y > 0)
+                .map(|row| row.extent),
+        )
+        .fold(Vec2::zero(), Vec2::max);
+    let size = Vec2::new(width.unwrap_or(extent.x), height.unwrap_or(extent.y));
+    let mut map = Map::new(size);
+    // An inferred size grows to fit every row, so validation must be told
+    // that rows running off the map cannot be detected.
+    map.size_inferred = width.is_none() || height.is_none();
 
     // More minor roads (with higher ranks) will be inserted before more major
     // roads. If the two roads intersect at a cell, then the last road to
@@ -31,55 +44,96 @@ where
     // cell will contain the ID of the more major road that passes through it.
     row_roads.sort_by_key(|row| row.rank);
     row_roads.reverse();
-    row_roads
+    row_roads.into_iter().for_each(|row| {
+        map.add_road(
+            row.origin,
+            row.length,
+            row.orientation,
+            row.rank,
+            row.name,
+            row.line,
+        )
+    });
+
+    row_buildings
         .into_iter()
-        .for_each(|row| map.add_road(row.origin, row.length, row.orientation, row.rank, row.name));
+        .for_each(|row| map.add_building(row.origin, row.dim, row.name, row.line));
 
-    let mut reader = csv::Reader::from_reader(building_csv_reader);
+    Ok(map)
+}
+
+/// Reads every record of the CSV, checking first that the header contains the
+/// table's columns.
+fn read_rows<R, T>(
+    reader: R,
+    table: CsvTable,
+    parse_row: fn(&Row) -> Result<T, ReadError>,
+) -> Result<Vec<T>, ReadError>
+where
+    R: io::Read,
+{
+    let mut reader = csv::Reader::from_reader(reader);
+    let header = reader
+        .headers()
+        .map_err(|e| ReadError::Csv(table, e))
+        .and_then(|headers| Header::new(table, headers))?;
     reader
         .records()
         .map(|rslt| {
-            let record = rslt.map_err(ReadError::BuildingCsv)?;
-            parse_building_row(&record).ok_or(ReadError::ParseBuilding(record))
+            let record = rslt.map_err(|e| ReadError::Csv(table, e))?;
+            parse_row(&Row {
+                header: &header,
+                record: &record,
+            })
         })
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .for_each(|row| map.add_building(row.origin, row.dim, row.name));
-
-    Ok(map)
+        .collect()
 }
 
-fn parse_road_row(record: &StringRecord) -> Option<RoadCsvRow> {
-    let orientation = record.get(0).and_then(parse_orientation)?;
-    let origin_x = record.get(1).and_then(|s| str::parse::<usize>(s).ok())?;
-    let origin_y = record.get(2).and_then(|s| str::parse::<usize>(s).ok())?;
-    let length = record.get(3).and_then(|s| str::parse::<usize>(s).ok())?;
-    let rank = record.get(4).and_then(|s| str::parse::<u8>(s).ok())?;
-    let name = record.get(5).and_then(non_empty);
+fn parse_road_row(row: &Row) -> Result<RoadCsvRow, ReadError> {
+    let orientation = row.parse_with("orientation", parse_orientation)?;
+    let origin_x = row.parse("origin_x")?;
+    let origin_y = row.parse("origin_y")?;
+    let length = row.parse("length")?;
+    let rank = row.parse("rank")?;
+    let name = row.get("name").and_then(non_empty);
 
     let origin = Vec2::new(origin_x, origin_y);
-    let road = RoadCsvRow::new(origin, length, orientation, rank, name);
-    Some(road)
+    let extent = match orientation {
+        RoadOrientation::NorthSouth => Vec2::new(
+            row.end("origin_x", origin_x, 1)?,
+            row.end("length", origin_y, length)?,
+        ),
+        RoadOrientation::EastWest => Vec2::new(
+            row.end("length", origin_x, length)?,
+            row.end("origin_y", origin_y, 1)?,
+        ),
+    };
+    let road = RoadCsvRow::new(origin, length, orientation, rank, name, row.line(), extent);
+    Ok(road)
 }
 
-fn parse_building_row(record: &StringRecord) -> Option<BuildingCsvRow> {
-    let origin_x = record.get(0).and_then(|s| str::parse::<usize>(s).ok())?;
-    let origin_y = record.get(1).and_then(|s| str::parse::<usize>(s).ok())?;
-    let dim_x = record.get(2).and_then(|s| str::parse::<usize>(s).ok())?;
-    let dim_y = record.get(3).and_then(|s| str::parse::<usize>(s).ok())?;
-    let name = record.get(4).and_then(non_empty);
+fn parse_building_row(row: &Row) -> Result<BuildingCsvRow, ReadError> {
+    let origin_x = row.parse("origin_x")?;
+    let origin_y = row.parse("origin_y")?;
+    let dim_x = row.parse("dim_x")?;
+    let dim_y = row.parse("dim_y")?;
+    let name = row.get("name").and_then(non_empty);
 
     let origin = Vec2::new(origin_x, origin_y);
     let dim = Vec2::new(dim_x, dim_y);
-    let building = BuildingCsvRow::new(origin, dim, name);
-    Some(building)
+    let extent = Vec2::new(
+        row.end("dim_x", origin_x, dim_x)?,
+        row.end("dim_y", origin_y, dim_y)?,
+    );
+    let building = BuildingCsvRow::new(origin, dim, name, row.line(), extent);
+    Ok(building)
 }
 
-fn parse_orientation(s: &str) -> Option<RoadOrientation> {
+fn parse_orientation(s: &str) -> Result<RoadOrientation, FieldError> {
     match s {
-        "ns" => Some(RoadOrientation::NorthSouth),
-        "ew" => Some(RoadOrientation::EastWest),
-        _ => None,
+        "ns" => Ok(RoadOrientation::NorthSouth),
+        "ew" => Ok(RoadOrientation::EastWest),
+        _ => Err(FieldError::Orientation),
     }
 }
 
@@ -90,16 +144,117 @@ fn non_empty(s: &str) -> Option<String> {
     }
 }
 
+/// The positions of a table's columns within its CSV.
+struct Header {
+    table: CsvTable,
+    indices: HashMap<&'static str, usize>,
+}
+
+impl Header {
+    fn new(table: CsvTable, headers: &StringRecord) -> Result<Self, ReadError> {
+        let indices = table
+            .columns()
+            .iter()
+            .filter_map(|&column| {
+                headers
+                    .iter()
+                    .position(|h| h.trim() == column)
+                    .map(|idx| (column, idx))
+            })
+            .collect::<HashMap<_, _>>();
+
+        if let Some(column) = table
+            .required_columns()
+            .iter()
+            .find(|c| !indices.contains_key(*c))
+        {
+            return Err(ReadError::MissingColumn(table, column));
+        }
+
+        Ok(Self { table, indices })
+    }
+}
+
+/// A record from a CSV along with the header needed to look up its fields.
+struct Row<'a> {
+    header: &'a Header,
+    record: &'a StringRecord,
+}
+
+impl Row<'_> {
+    /// Returns the line of the CSV on which the record starts.
+    fn line(&self) -> Option<usize> {
+        self.record.position().map(|p| p.line() as usize)
+    }
+
+    fn get(&self, column: &'static str) -> Option<&str> {
+        self.header
+            .indices
+            .get(column)
+            .and_then(|idx| self.record.get(*idx))
+    }
+
+    fn parse<T>(&self, column: &'static str) -> Result<T, ReadError>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        self.parse_with(column, |s| s.parse().map_err(FieldError::Number))
+    }
+
+    fn parse_with<T>(
+        &self,
+        column: &'static str,
+        parse: impl Fn(&str) -> Result<T, FieldError>,
+    ) -> Result<T, ReadError> {
+        let value = self.get(column).unwrap_or_default().trim();
+        parse(value).map_err(|reason| self.invalid_field(column, reason))
+    }
+
+    /// Returns the coordinate just past the end of a span of `len` cells that
+    /// starts at `origin`, blaming `column` if it does not fit in a `usize`.
+    fn end(&self, column: &'static str, origin: usize, len: usize) -> Result<usize, ReadError> {
+        origin
+            .checked_add(len)
+            .ok_or_else(|| self.invalid_field(column, FieldError::TooLarge))
+    }
+
+    fn invalid_field(&self, column: &'static str, reason: FieldError) -> ReadError {
+        ReadError::InvalidField {
+            table: self.header.table,
+            line: self.line().unwrap_or_default(),
+            column,
+            value: self.get(column).unwrap_or_default().trim().to_string(),
+            reason,
+        }
+    }
+}
+
 /// Row from the buildings CSV.
 struct BuildingCsvRow {
     origin: Vec2<usize>,
     dim: Vec2<usize>,
     name: Option<String>,
+    line: Option<usize>,
+
+    /// The coordinates just past the building's far corner.
+    extent: Vec2<usize>,
 }
 
 impl BuildingCsvRow {
-    pub fn new(origin: Vec2<usize>, dim: Vec2<usize>, name: Option<String>) -> Self {
-        Self { origin, dim, name }
+    pub fn new(
+        origin: Vec2<usize>,
+        dim: Vec2<usize>,
+        name: Option<String>,
+        line: Option<usize>,
+        extent: Vec2<usize>,
+    ) -> Self {
+        Self {
+            origin,
+            dim,
+            name,
+            line,
+            extent,
+        }
     }
 }
 
@@ -110,6 +265,10 @@ struct RoadCsvRow {
     length: usize,
     name: Option<String>,
     rank: u8,
+    line: Option<usize>,
+
+    /// The coordinates just past the road's far end.
+    extent: Vec2<usize>,
 }
 
 impl RoadCsvRow {
@@ -119,6 +278,8 @@ impl RoadCsvRow {
         orientation: RoadOrientation,
         rank: u8,
         name: Option<String>,
+        line: Option<usize>,
+        extent: Vec2<usize>,
     ) -> Self {
         Self {
             origin,
@@ -126,18 +287,148 @@ impl RoadCsvRow {
             orientation,
             rank,
             name,
+            line,
+            extent,
+        }
+    }
+}
+
+/// One of the CSVs that describe a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvTable {
+    Roads,
+    Buildings,
+}
+
+impl CsvTable {
+    /// Returns the columns that every CSV of this table must have.
+    fn required_columns(&self) -> &'static [&'static str] {
+        match self {
+            CsvTable::Roads => &["orientation", "origin_x", "origin_y", "length", "rank"],
+            CsvTable::Buildings => &["origin_x", "origin_y", "dim_x", "dim_y"],
+        }
+    }
+
+    /// Returns every column that is read from this table, including optional
+    /// ones.
+    fn columns(&self) -> Vec<&'static str> {
+        let mut columns = self.required_columns().to_vec();
+        columns.push("name");
+        columns
+    }
+}
+
+impl Display for CsvTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvTable::Roads => write!(f, "roads"),
+            CsvTable::Buildings => write!(f, "buildings"),
         }
     }
 }
 
 #[derive(Debug, Error)]
 pub enum ReadError {
-    #[error("failed to read road csv")]
-    RoadCsv(csv::Error),
-    #[error("failed to read building csv")]
-    BuildingCsv(csv::Error),
-    #[error("failed to parse road in csv")]
-    ParseRoad(StringRecord),
-    #[error("failed to parse building in csv")]
-    ParseBuilding(StringRecord),
+    #[error("failed to read the {0} CSV")]
+    Csv(CsvTable, #[source] csv::Error),
+
+    #[error(r#"the {0} CSV has no "{1}" column"#)]
+    MissingColumn(CsvTable, &'static str),
+
+    #[error(
+        r#"line {line} of the {table} CSV has an invalid "{column}" value "{value}": {reason}"#
+    )]
+    InvalidField {
+        table: CsvTable,
+        line: usize,
+        column: &'static str,
+        value: String,
+        reason: FieldError,
+    },
+}
+
+impl ReadError {
+    /// Returns the table in which the error occurred.
+    pub fn table(&self) -> CsvTable {
+        match self {
+            ReadError::Csv(table, _)
+            | ReadError::MissingColumn(table, _)
+            | ReadError::InvalidField { table, .. } => *table,
+        }
+    }
+}
+
+/// The reason a field of a CSV could not be read.
+#[derive(Debug, Error)]
+pub enum FieldError {
+    #[error(r#"expected "ns" or "ew""#)]
+    Orientation,
+
+    #[error(transparent)]
+    Number(ParseIntError),
+
+    #[error("the road or building would extend past the largest possible map")]
+    TooLarge,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_csvs, CsvTable, FieldError, ReadError};
+    use vek::Vec2;
+
+    #[test]
+    fn infer_size_and_ignore_extra_columns() {
+        let roads = "name,color,orientation,origin_x,origin_y,length,rank\n\
+                     Calle 1,red,ew,0,2,6,0\n";
+        let buildings = "category,origin_x,origin_y,dim_x,dim_y,name\n\
+                         shop,2,3,1,4,la tienda\n";
+
+        let map = from_csvs(None, None, roads.as_bytes(), buildings.as_bytes()).unwrap();
+        assert_eq!(map.size(), Vec2::new(6, 7));
+
+        let map = from_csvs(Some(10), None, roads.as_bytes(), buildings.as_bytes()).unwrap();
+        assert_eq!(map.size(), Vec2::new(10, 7));
+    }
+
+    #[test]
+    fn report_invalid_fields() {
+        let buildings = "origin_x,origin_y,dim_x,dim_y,name\n";
+
+        let roads = "orientation,origin_x,origin_y,length,rank\nns,1,0,3,0\nnw,1,0,3,0\n";
+        let err = from_csvs(None, None, roads.as_bytes(), buildings.as_bytes()).unwrap_err();
+        assert!(matches!(
+            err,
+            ReadError::InvalidField {
+                table: CsvTable::Roads,
+                line: 3,
+                column: "orientation",
+                ..
+            }
+        ));
+
+        let roads = "orientation,origin_x,origin_y,length,rank\nns,1,0,3,256\n";
+        let err = from_csvs(None, None, roads.as_bytes(), buildings.as_bytes()).unwrap_err();
+        assert!(matches!(
+            err,
+            ReadError::InvalidField { column: "rank", .. }
+        ));
+
+        let roads = format!(
+            "orientation,origin_x,origin_y,length,rank\nns,1,{},3,0\n",
+            usize::MAX
+        );
+        let err = from_csvs(None, None, roads.as_bytes(), buildings.as_bytes()).unwrap_err();
+        assert!(matches!(
+            err,
+            ReadError::InvalidField {
+                column: "length",
+                reason: FieldError::TooLarge,
+                ..
+            }
+        ));
+
+        let roads = "orientation,origin_x,origin_y,length\n";
+        let err = from_csvs(None, None, roads.as_bytes(), buildings.as_bytes()).unwrap_err();
+        assert!(matches!(err, ReadError::MissingColumn(_, "rank")));
+    }
 }
@@ -42,6 +42,9 @@ pub struct Road {
     /// Larger roads, such as multi-lane highways, have a smaller rank.
     /// Smaller roads, such as dirt roads, have a larger rank.
     rank: u8,
+
+    /// Line of the CSV from which the road was read, if any.
+    line: Option<usize>,
 }
 
 impl Road {
@@ -53,6 +56,7 @@ impl Road {
         orientation: RoadOrientation,
         rank: u8,
         name: Option<String>,
+        line: Option<usize>,
     ) -> Self {
         Self {
             id,
@@ -61,6 +65,7 @@ impl Road {
             orientation,
             rank,
             name,
+            line,
         }
     }
 
@@ -74,6 +79,33 @@ impl Road {
         self.origin
     }
 
+    /// Returns the name of the road.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the number of cells the road covers.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the line of the CSV from which the road was read, if any.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Returns the rank of the road. Smaller ranks are more major roads.
+    pub fn rank(&self) -> u8 {
+        self.rank
+    }
+
+    /// Returns `true` if the given grid coordinates are on the road.
+    pub fn contains(&self, idx: Vec2<usize>) -> bool {
+        let min = self.origin();
+        let max = self.terminus();
+        self.length > 0 && (min.x..=max.x).contains(&idx.x) && (min.y..=max.y).contains(&idx.y)
+    }
+
     /// Returns the orientation of the road.
     pub fn orientation(&self) -> RoadOrientation {
         self.orientation
@@ -82,8 +114,8 @@ impl Road {
     /// Returns the coordinates at which the road ends.
     pub fn terminus(&self) -> Vec2<usize> {
         let delta = match self.orientation {
-            RoadOrientation::NorthSouth => Vec2::new(0, self.length - 1),
-            RoadOrientation::EastWest => Vec2::new(self.length - 1, 0),
+            RoadOrientation::NorthSouth => Vec2::new(0, self.length.saturating_sub(1)),
+            RoadOrientation::EastWest => Vec2::new(self.length.saturating_sub(1), 0),
         };
         self.origin + delta
     }
@@ -0,0 +1,237 @@
+use super::{Building, Cell, Map, Road};
+use crate::direction::CardinalDirection;
+use iter_tools::Itertools;
+use std::fmt::Display;
+use thiserror::Error;
+use vek::Vec2;
+
+impl Map {
+    /// Checks the map for roads and buildings that would make the game behave
+    /// unexpectedly and returns every problem found.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+
+        for road in &self.roads {
+            let location = Location::road(road);
+            if road.length() == 0 {
+                errors.push(ValidationError::EmptyRoad(location));
+            } else if !self.in_bounds(road.origin()) || !self.in_bounds(road.terminus()) {
+                errors.push(ValidationError::RoadOutOfBounds(location));
+            }
+        }
+
+        for building in &self.buildings {
+            let location = Location::building(building);
+            if building.dim().x == 0 || building.dim().y == 0 {
+                errors.push(ValidationError::EmptyBuilding(location));
+            } else if !self.in_bounds(building.min()) || !self.in_bounds(building.max()) {
+                errors.push(ValidationError::BuildingOutOfBounds(location));
+            }
+        }
+
+        for building in &self.buildings {
+            let cells = self.building_indices(building);
+            for road in &self.roads {
+                if self
+                    .road_indices(road)
+                    .iter()
+                    .any(|idx| cells.contains(idx))
+                {
+                    errors.push(ValidationError::BuildingOverlapsRoad(
+                        Location::building(building),
+                        Location::road(road),
+                    ));
+                }
+            }
+        }
+
+        for (a, b) in self.buildings.iter().tuple_combinations() {
+            let b_cells = self.building_indices(b);
+            if self
+                .building_indices(a)
+                .iter()
+                .any(|idx| b_cells.contains(idx))
+            {
+                errors.push(ValidationError::BuildingsOverlap(
+                    Location::building(a),
+                    Location::building(b),
+                ));
+            }
+        }
+
+        for building in &self.buildings {
+            let has_road = self
+                .building_indices(building)
+                .into_iter()
+                .filter(|idx| self.in_bounds(*idx))
+                .flat_map(|idx| self.get_neighbors(idx).into_vec())
+                .any(Cell::is_road);
+            if !has_road {
+                errors.push(ValidationError::BuildingWithoutRoad(Location::building(
+                    building,
+                )));
+            }
+        }
+
+        errors.extend(
+            self.disconnected_roads()
+                .into_iter()
+                .map(|road| ValidationError::DisconnectedRoad(Location::road(road))),
+        );
+
+        errors
+    }
+
+    fn in_bounds(&self, idx: Vec2<usize>) -> bool {
+        let size = self.size();
+        idx.x < size.x && idx.y < size.y
+    }
+
+    /// Returns the roads that cannot be reached from the largest connected
+    /// group of road cells.
+    fn disconnected_roads(&self) -> Vec<&Road> {
+        let size = self.size();
+        let mut component = vec![None; size.x * size.y];
+        let mut component_sizes: Vec<usize> = vec![];
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let start = Vec2::new(x, y);
+                if component[y * size.x + x].is_some() || !self.is_road(start) {
+                    continue;
+                }
+
+                let id = component_sizes.len();
+                let mut count = 0;
+                let mut stack = vec![start];
+                component[y * size.x + x] = Some(id);
+                while let Some(idx) = stack.pop() {
+                    count += 1;
+                    for n in CardinalDirection::all()
+                        .into_iter()
+                        .filter_map(|dir| self.neighbor_index(idx, dir))
+                    {
+                        if component[n.y * size.x + n.x].is_none() && self.is_road(n) {
+                            component[n.y * size.x + n.x] = Some(id);
+                            stack.push(n);
+                        }
+                    }
+                }
+                component_sizes.push(count);
+            }
+        }
+
+        let Some((main, _)) = component_sizes.iter().enumerate().max_by_key(|(_, n)| **n) else {
+            return vec![];
+        };
+
+        self.roads
+            .iter()
+            .filter(|road| {
+                self.road_indices(road)
+                    .into_iter()
+                    .filter(|idx| self.in_bounds(*idx))
+                    .filter_map(|idx| component[idx.y * size.x + idx.x])
+                    .all(|c| c != main)
+            })
+            .filter(|road| road.length() > 0)
+            .collect()
+    }
+
+    fn is_road(&self, idx: Vec2<usize>) -> bool {
+        self.get(idx).is_some_and(Cell::is_road)
+    }
+}
+
+/// A problem with a map's roads or buildings.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationError {
+    #[error("{0} has a length of zero")]
+    EmptyRoad(Location),
+
+    #[error("{0} has a width or height of zero")]
+    EmptyBuilding(Location),
+
+    #[error("{0} runs off the map")]
+    RoadOutOfBounds(Location),
+
+    #[error("{0} extends off the map")]
+    BuildingOutOfBounds(Location),
+
+    #[error("{0} overlaps {1}")]
+    BuildingOverlapsRoad(Location, Location),
+
+    #[error("{0} overlaps {1}")]
+    BuildingsOverlap(Location, Location),
+
+    #[error("{0} is not next to any road")]
+    BuildingWithoutRoad(Location),
+
+    #[error("{0} is not connected to the rest of the roads")]
+    DisconnectedRoad(Location),
+}
+
+/// Identifies a road or building by the CSV line it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    Road(Option<usize>),
+    Building(Option<usize>),
+}
+
+impl Location {
+    fn road(road: &Road) -> Self {
+        Location::Road(road.line())
+    }
+
+    fn building(building: &Building) -> Self {
+        Location::Building(building.line())
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Location::Road(Some(line)) => write!(f, "the road on line {line} of the roads CSV"),
+            Location::Road(None) => write!(f, "a road"),
+            Location::Building(Some(line)) => {
+                write!(f, "the building on line {line} of the buildings CSV")
+            }
+            Location::Building(None) => write!(f, "a building"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Location, ValidationError};
+    use crate::map::fixture;
+
+    #[test]
+    fn validate_broken_map() {
+        let roads = ["ns,1,0,5,0,Calle 1", "ew,3,2,4,0,Calle 2", "ns,4,4,0,0,"];
+        let buildings = ["0,0,2,1,la casa", "3,4,1,1,el parque"];
+        let map = fixture::sized_map(Some(5), Some(5), &roads, &buildings);
+
+        let errors = map.validate();
+        assert!(errors.contains(&ValidationError::EmptyRoad(Location::Road(Some(4)))));
+        assert!(errors.contains(&ValidationError::RoadOutOfBounds(Location::Road(Some(3)))));
+        assert!(errors.contains(&ValidationError::BuildingOverlapsRoad(
+            Location::Building(Some(2)),
+            Location::Road(Some(2))
+        )));
+        assert!(
+            errors.contains(&ValidationError::BuildingWithoutRoad(Location::Building(
+                Some(3)
+            )))
+        );
+        assert!(errors.contains(&ValidationError::DisconnectedRoad(Location::Road(Some(3)))));
+        assert!(!map.size_inferred());
+
+        // Without a declared width the map grows to fit the road that runs off
+        // it, which validation cannot tell apart from an intended road.
+        let map = fixture::sized_map(None, Some(5), &roads, &buildings);
+        let errors = map.validate();
+        assert!(map.size_inferred());
+        assert!(!errors.contains(&ValidationError::RoadOutOfBounds(Location::Road(Some(3)))));
+    }
+}
@@ -7,7 +7,7 @@ use std::fmt::Display;
 use vek::Vec2;
 
 /// A position and direction in the game world.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Pose {
     position: Vec2<usize>,
     direction: CardinalDirection,
@@ -53,14 +53,9 @@ impl Pose {
 
     /// Returns a new pose that has been rotated in the specified direction.
     fn rotate(&self, dir: TurnDirection) -> Self {
-        let new_direction = match dir {
-            TurnDirection::Left => self.direction.left(),
-            TurnDirection::Right => self.direction.right(),
-        };
-
         Self {
             position: self.position,
-            direction: new_direction,
+            direction: self.direction.turn(dir),
         }
     }
 
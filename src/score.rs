@@ -0,0 +1,144 @@
+//! This module records the route a player takes and grades it against the
+//! best route to the goal.
+
+use std::fmt::Display;
+
+use crate::cmd::AbsoluteCommand;
+use crate::solve::{self, Route};
+
+/// The points taken off the score for each sentence that could not be
+/// followed.
+const ERROR_PENALTY: usize = 5;
+
+/// What the player has done so far in a level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trajectory {
+    /// Number of cells walked.
+    pub cells: usize,
+
+    /// Number of rotations made, counting a U-turn as one rotation.
+    pub turns: usize,
+
+    /// Number of sentences that were followed.
+    pub sentences: usize,
+
+    /// Number of times the player tried to follow a sentence that had an
+    /// error.
+    pub errors: usize,
+}
+
+impl Trajectory {
+    /// Records the commands produced by following the given number of
+    /// sentences.
+    pub fn record(&mut self, sentences: usize, cmds: &[AbsoluteCommand]) {
+        self.sentences += sentences;
+        for cmd in cmds {
+            match cmd {
+                AbsoluteCommand::Forward(dist) => self.cells += dist,
+                AbsoluteCommand::Rotate(_) => self.turns += 1,
+            }
+        }
+    }
+
+    pub fn record_error(&mut self) {
+        self.errors += 1;
+    }
+
+    /// Returns the weighted cost of the cells walked and rotations made, in
+    /// the same units as `Route::cost`.
+    pub fn cost(&self) -> usize {
+        solve::cost(self.cells, self.turns)
+    }
+}
+
+/// A grade out of 100 for how efficiently the player reached the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    pub trajectory: Trajectory,
+    pub optimal: Route,
+}
+
+impl Score {
+    pub fn new(trajectory: Trajectory, optimal: Route) -> Self {
+        Self {
+            trajectory,
+            optimal,
+        }
+    }
+
+    /// Returns how close the player's route was to the best route as a
+    /// percentage, from 0 to 100.
+    pub fn efficiency(&self) -> usize {
+        // A player who starts beside the goal needs to do nothing at all.
+        (100 * self.optimal.cost())
+            .checked_div(self.trajectory.cost())
+            .map_or(100, |pct| pct.min(100))
+    }
+
+    /// Returns the efficiency less a penalty for each error, from 0 to 100.
+    pub fn points(&self) -> usize {
+        self.efficiency()
+            .saturating_sub(ERROR_PENALTY * self.trajectory.errors)
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let t = &self.trajectory;
+        writeln!(
+            f,
+            "Cells walked: {} (best: {})",
+            t.cells,
+            self.optimal.distance()
+        )?;
+        writeln!(
+            f,
+            "Turns made: {} (best: {})",
+            t.turns,
+            self.optimal.turns()
+        )?;
+        writeln!(f, "Sentences used: {}", t.sentences)?;
+        writeln!(f, "Errors: {} (-{} each)", t.errors, ERROR_PENALTY)?;
+        writeln!(f, "Efficiency: {}%", self.efficiency())?;
+        writeln!(f, "Score: {}/100", self.points())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Score, Trajectory};
+    use crate::cmd::AbsoluteCommand;
+    use crate::direction::TurnDirection;
+    use crate::solve::Route;
+
+    #[test]
+    fn score_against_optimal() {
+        let optimal = Route {
+            cmds: vec![
+                AbsoluteCommand::Forward(4),
+                AbsoluteCommand::Rotate(TurnDirection::Right),
+                AbsoluteCommand::Forward(4),
+            ],
+        };
+
+        let mut trajectory = Trajectory::default();
+        trajectory.record(1, &optimal.cmds);
+        assert_eq!(
+            Score::new(trajectory.clone(), optimal.clone()).points(),
+            100
+        );
+
+        // Turning around and walking back costs as much as the best route.
+        trajectory.record(
+            2,
+            &[
+                AbsoluteCommand::Rotate(TurnDirection::Around),
+                AbsoluteCommand::Forward(8),
+            ],
+        );
+        trajectory.record_error();
+        let score = Score::new(trajectory, optimal);
+        assert_eq!(score.efficiency(), 50);
+        assert_eq!(score.points(), 45);
+    }
+}
@@ -0,0 +1,161 @@
+//! This module finds the best route from a pose to a goal building.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::cmd::AbsoluteCommand;
+use crate::direction::TurnDirection;
+use crate::map::{BuildingId, Map};
+use crate::pose::Pose;
+
+/// The cost of a rotation, measured in the number of cells the player could
+/// walk instead.
+const TURN_COST: usize = 2;
+
+/// A sequence of commands that takes the player to the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub cmds: Vec<AbsoluteCommand>,
+}
+
+impl Route {
+    /// Returns the number of cells walked along the route.
+    pub fn distance(&self) -> usize {
+        self.cmds
+            .iter()
+            .map(|cmd| match cmd {
+                AbsoluteCommand::Forward(dist) => *dist,
+                AbsoluteCommand::Rotate(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Returns the number of rotations made along the route.
+    pub fn turns(&self) -> usize {
+        self.cmds
+            .iter()
+            .filter(|cmd| matches!(cmd, AbsoluteCommand::Rotate(_)))
+            .count()
+    }
+
+    /// Returns the weighted cost of the route, the quantity that `solve`
+    /// minimizes.
+    pub fn cost(&self) -> usize {
+        cost(self.distance(), self.turns())
+    }
+}
+
+/// Returns the weighted cost of walking the given number of cells and making
+/// the given number of rotations.
+pub fn cost(distance: usize, turns: usize) -> usize {
+    distance + TURN_COST * turns
+}
+
+/// Finds the cheapest route from the starting pose to any position beside the
+/// goal building, or `None` if the goal cannot be reached.
+///
+/// Every cell walked costs one and every rotation costs `TURN_COST`.
+pub fn solve(map: &Map, start: &Pose, goal: BuildingId) -> Option<Route> {
+    // Each step is either a single cell forward or a rotation. Consecutive
+    // steps forward are merged into a single command once the route is found.
+    let mut costs: HashMap<Pose, usize> = HashMap::from([(*start, 0)]);
+    let mut prev: HashMap<Pose, (Pose, AbsoluteCommand)> = HashMap::new();
+    // The queue holds indices into `poses`, since poses have no ordering.
+    let mut poses = vec![*start];
+    let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+
+    while let Some(Reverse((cost, i))) = queue.pop() {
+        let pose = poses[i];
+        if costs.get(&pose).is_some_and(|c| *c < cost) {
+            continue;
+        }
+        if map.is_beside(pose.position(), goal) {
+            return Some(Route {
+                cmds: merge_forwards(trace_back(&prev, start, pose)),
+            });
+        }
+
+        let rotations = [
+            TurnDirection::Left,
+            TurnDirection::Right,
+            TurnDirection::Around,
+        ]
+        .map(|dir| (AbsoluteCommand::Rotate(dir), TURN_COST));
+        let forward = pose
+            .step_forward(map)
+            .map(|_| (AbsoluteCommand::Forward(1), 1));
+
+        for (cmd, step_cost) in rotations.into_iter().chain(forward) {
+            let next = pose.apply_cmd(&cmd);
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|c| next_cost < *c) {
+                costs.insert(next, next_cost);
+                prev.insert(next, (pose, cmd));
+                queue.push(Reverse((next_cost, poses.len())));
+                poses.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the single-step commands that lead from the start to the end pose.
+fn trace_back(
+    prev: &HashMap<Pose, (Pose, AbsoluteCommand)>,
+    start: &Pose,
+    end: Pose,
+) -> Vec<AbsoluteCommand> {
+    let mut cmds = vec![];
+    let mut pose = end;
+    while pose != *start {
+        let (p, cmd) = prev[&pose];
+        cmds.push(cmd);
+        pose = p;
+    }
+    cmds.reverse();
+    cmds
+}
+
+/// Combines runs of `Forward` commands into a single `Forward` command.
+fn merge_forwards(cmds: Vec<AbsoluteCommand>) -> Vec<AbsoluteCommand> {
+    let mut merged: Vec<AbsoluteCommand> = vec![];
+    for cmd in cmds {
+        match (merged.last_mut(), cmd) {
+            (Some(AbsoluteCommand::Forward(total)), AbsoluteCommand::Forward(dist)) => {
+                *total += dist
+            }
+            _ => merged.push(cmd),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+    use crate::cmd::AbsoluteCommand;
+    use crate::direction::{CardinalDirection, TurnDirection};
+    use crate::map::{fixture, BuildingId};
+    use crate::pose::Pose;
+
+    #[test]
+    fn solve_turn_at_intersection() {
+        let map = fixture::map(
+            &["ns,2,0,7,1,Carrera 1", "ew,0,4,7,0,Calle 1"],
+            &["6,5,1,1,la casa"],
+        );
+
+        // The house is diagonally beside the road at (5, 4).
+        let start = Pose::new(2, 0, CardinalDirection::North);
+        let route = solve(&map, &start, BuildingId::new(0)).unwrap();
+        assert_eq!(
+            route.cmds,
+            vec![
+                AbsoluteCommand::Forward(4),
+                AbsoluteCommand::Rotate(TurnDirection::Right),
+                AbsoluteCommand::Forward(3),
+            ]
+        );
+    }
+}
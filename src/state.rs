@@ -1,74 +1,180 @@
+use std::cell::OnceCell;
 use std::fmt::Display;
 
 use iter_tools::Itertools;
 use thiserror::Error;
 
 use crate::cmd::{transform_cmds, AbsoluteCommand, CtxCommand, CtxCommandDistance};
-use crate::lang::{LexError, Lexeme, Sentence, SentenceParseError};
-use crate::map::{BuildingId, Cell, Map};
+use crate::lang::{
+    syntax::StreetName, Accents, Diagnostic, Directions, DirectionsParseError, LexError, Lexeme,
+    Register, WordError,
+};
+use crate::map::{BuildingId, Map, RoadGraph};
 use crate::pose::Pose;
+use crate::score::{Score, Trajectory};
+use crate::solve::{solve, Route};
 use crate::ui::UiGrid;
 
 /// The state of the game.
 pub struct State {
     map: Map,
 
-    word_bank: Vec<Lexeme>,
+    /// The roads of the map, which every sentence is read against.
+    graph: RoadGraph,
+
+    /// The words that may be used in a sentence, or `None` if every word may
+    /// be used.
+    word_bank: Option<Vec<Lexeme>>,
+
+    /// The form of address that verbs must use, if the level requires one.
+    register: Option<Register>,
+
+    /// Whether words that are missing accents are errors.
+    accents: Accents,
 
     sentence: String,
 
+    /// The pose at which the player started the level.
+    start: Pose,
+
     pose: Pose,
 
     goal: BuildingId,
+
+    /// What the player has done since the start of the level.
+    trajectory: Trajectory,
+
+    /// The best route from the start to the goal, if there is one. It is only
+    /// searched for once the player is scored.
+    optimal: OnceCell<Option<Route>>,
 }
 
 impl State {
-    pub fn new(map: Map, word_bank: Vec<Lexeme>, pose: Pose, goal: BuildingId) -> Self {
+    pub fn new(
+        map: Map,
+        graph: RoadGraph,
+        word_bank: Option<Vec<Lexeme>>,
+        register: Option<Register>,
+        accents: Accents,
+        pose: Pose,
+        goal: BuildingId,
+    ) -> Self {
         Self {
             map,
+            graph,
             word_bank,
+            register,
+            accents,
             sentence: "".to_string(),
+            start: pose,
             pose,
             goal,
+            trajectory: Trajectory::default(),
+            optimal: OnceCell::new(),
         }
     }
 
-    pub fn map(&self) -> &Map {
-        &self.map
+    /// Returns the words that may be used in a sentence.
+    pub fn word_bank(&self) -> Vec<Lexeme> {
+        self.word_bank.clone().unwrap_or_else(Lexeme::all)
     }
 
-    pub fn word_bank(&self) -> &Vec<Lexeme> {
-        &self.word_bank
+    /// Returns whether the lexeme at the given index of the sentence may be
+    /// used. A numeral that follows a kind of street, as in "Calle 87", is part
+    /// of the street's name and is always allowed. Any other numeral, as in
+    /// "7 quadras", must be in the word bank like any other word.
+    fn in_word_bank(&self, lexemes: &[Lexeme], i: usize) -> bool {
+        let Some(word_bank) = &self.word_bank else {
+            return true;
+        };
+        let names_street = i > 0 && StreetName::is_kind(&lexemes[i - 1]);
+        match lexemes[i] {
+            Lexeme::Numeral(_) if names_street => true,
+            lexeme => word_bank.contains(&lexeme),
+        }
     }
 
     pub fn set_sentence(&mut self, val: String) {
         self.sentence = val;
     }
 
+    /// Parses the current sentence into directions.
+    pub fn directions(&self) -> Result<Directions, CommandError> {
+        let lexemes = Lexeme::parse_line_with(&self.sentence, self.accents, &self.word_bank())?;
+        let not_in_bank = (0..lexemes.len())
+            .filter(|i| !self.in_word_bank(&lexemes, *i))
+            .map(|i| lexemes[i])
+            .unique()
+            .collect_vec();
+        if !not_in_bank.is_empty() {
+            return Err(CommandError::WordsNotInBank(not_in_bank));
+        }
+        if let Some(register) = self.register {
+            let wrong_register = lexemes
+                .iter()
+                .filter(|l| !register.allows(**l))
+                .unique()
+                .copied()
+                .collect_vec();
+            if !wrong_register.is_empty() {
+                return Err(CommandError::WrongRegister(register, wrong_register));
+            }
+        }
+        let syntax_tree =
+            Directions::parse(&lexemes).map_err(|source| CommandError::SentenceParse {
+                line: self.sentence.clone(),
+                source,
+            })?;
+        Ok(syntax_tree)
+    }
+
     pub fn cmds_from_sentence(&self) -> Result<Vec<AbsoluteCommand>, CommandError> {
-        let lexemes = Lexeme::parse_line(&self.sentence)?;
-        let syntax_tree = Sentence::parse(&lexemes)?;
-        let ctx_cmds: Vec<CtxCommand> = syntax_tree.into();
-        let abs_cmds =
-            transform_cmds(&ctx_cmds, &self.map, &self.pose).map_err(CommandError::CmdTransform)?;
+        self.cmds_from_directions(self.directions()?)
+    }
+
+    /// Transforms already parsed directions into commands from the player's
+    /// pose.
+    fn cmds_from_directions(
+        &self,
+        directions: Directions,
+    ) -> Result<Vec<AbsoluteCommand>, CommandError> {
+        let ctx_cmds: Vec<CtxCommand> = directions.into();
+        let abs_cmds = transform_cmds(&ctx_cmds, &self.map, &self.graph, &self.pose)
+            .map_err(CommandError::CmdTransform)?;
         Ok(abs_cmds)
     }
 
+    /// Moves the player by following the current sentence, recording the
+    /// result in the player's trajectory.
     pub fn apply_sentence_cmds(&mut self) -> Result<(), CommandError> {
-        let cmds = self.cmds_from_sentence()?;
-        self.pose = self.pose.apply_cmds(&cmds);
-        Ok(())
+        let result = self.directions().and_then(|directions| {
+            let sentences = directions.0.len();
+            Ok((sentences, self.cmds_from_directions(directions)?))
+        });
+        match result {
+            Ok((sentences, cmds)) => {
+                self.trajectory.record(sentences, &cmds);
+                self.pose = self.pose.apply_cmds(&cmds);
+                Ok(())
+            }
+            Err(e) => {
+                self.trajectory.record_error();
+                Err(e)
+            }
+        }
+    }
+
+    /// Grades the player's trajectory against the best route to the goal.
+    pub fn score(&self) -> Option<Score> {
+        let optimal = self
+            .optimal
+            .get_or_init(|| solve(&self.map, &self.start, self.goal))
+            .clone()?;
+        Some(Score::new(self.trajectory.clone(), optimal))
     }
 
     pub fn is_winning(&self) -> bool {
-        self.map
-            .get_neighbors(self.pose.position())
-            .into_vec()
-            .into_iter()
-            .any(|c| match c {
-                Cell::Building(b_id) => *b_id == self.goal,
-                _ => false,
-            })
+        self.map.is_beside(self.pose.position(), self.goal)
     }
 
     pub fn render_map(&self) {
@@ -85,8 +191,17 @@ impl Display for State {
 
         writeln!(f, "Current sentence: \"{}\"", self.sentence)?;
 
-        let cmd_or_err = self.cmds_from_sentence();
-        writeln!(f, "Command state: {:?}", cmd_or_err)
+        let cmds = match self.directions() {
+            Ok(directions) => {
+                writeln!(f, "Understood: \"{directions}\"")?;
+                self.cmds_from_directions(directions)
+            }
+            Err(e) => Err(e),
+        };
+        match cmds {
+            Ok(cmds) => writeln!(f, "Commands: {:?}", cmds),
+            Err(e) => writeln!(f, "{e}"),
+        }
     }
 }
 
@@ -98,9 +213,13 @@ pub enum CommandError {
         source: LexError,
     },
 
+    WordsNotInBank(Vec<Lexeme>),
+
+    WrongRegister(Register, Vec<Lexeme>),
+
     SentenceParse {
-        #[from]
-        source: SentenceParseError,
+        line: String,
+        source: DirectionsParseError,
     },
 
     CmdTransform(CtxCommand),
@@ -110,16 +229,46 @@ impl Display for CommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             CommandError::UnrecognizedWords {
-                source: LexError(words),
+                source: LexError(errors),
             } => {
-                let words_str = words.iter().map(|w| format!("\"{w}\"")).join(", ");
+                let (unknown, accents): (Vec<_>, Vec<_>) = errors
+                    .iter()
+                    .partition(|e| matches!(e, WordError::Unknown(_)));
+                if !unknown.is_empty() {
+                    write!(
+                        f,
+                        "These words are not recognized: {}",
+                        unknown.iter().join(", ")
+                    )?;
+                }
+                if !unknown.is_empty() && !accents.is_empty() {
+                    writeln!(f)?;
+                }
+                if !accents.is_empty() {
+                    write!(
+                        f,
+                        "These words are missing accents or have the wrong ones: {}",
+                        accents.iter().join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            CommandError::WordsNotInBank(lexemes) => {
+                let words_str = lexemes.iter().map(|l| format!("\"{l}\"")).join(", ");
                 write!(
                     f,
                     "You cannot use words that are not in the word bank: {words_str}"
                 )
             }
-            CommandError::SentenceParse { source: _ } => {
-                write!(f, "Could not parse sentence. (TODO: Add detail.)")
+            CommandError::WrongRegister(register, lexemes) => {
+                let words_str = lexemes.iter().map(|l| format!("\"{l}\"")).join(", ");
+                write!(
+                    f,
+                    "This level uses the {register} form of verbs, but these words do not: {words_str}"
+                )
+            }
+            CommandError::SentenceParse { line, source } => {
+                write!(f, "{}", Diagnostic::new(line, source))
             }
             CommandError::CmdTransform(ctx_cmd) => {
                 match ctx_cmd {
@@ -146,13 +295,142 @@ impl Display for CommandError {
                             CtxCommandDistance::NthStreet(n, None) => {
                                 write!(f, "There are not {n} intersecting streets past your current position.")
                             }
+                            CtxCommandDistance::PastNthStreet(1) => {
+                                write!(f, "There is no intersecting street to cross past your current position.")
+                            }
+                            CtxCommandDistance::PastNthStreet(n) => {
+                                write!(f, "There are not {n} intersecting streets to cross past your current position.")
+                            }
+                            CtxCommandDistance::LastStreet(Some(dir)) => {
+                                write!(f, "There is no intersecting street to the {dir} before the end of the street.")
+                            }
+                            CtxCommandDistance::LastStreet(None) => {
+                                write!(
+                                    f,
+                                    "There is no intersecting street before the end of the street."
+                                )
+                            }
+                            CtxCommandDistance::NamedStreet(name, Some(dir)) => {
+                                write!(f, "There is no intersecting street to the {dir} named {name} at or past your current position.")
+                            }
+                            CtxCommandDistance::NamedStreet(name, None) => {
+                                write!(f, "There is no street named {name} at or past your current position.")
+                            }
+                            CtxCommandDistance::Landmark(name, Some(dir)) => {
+                                write!(f, "There is no {name} to the {dir} at or past your current position.")
+                            }
+                            CtxCommandDistance::Landmark(name, None) => {
+                                write!(f, "There is no {name} at or past your current position.")
+                            }
+                            CtxCommandDistance::PastLandmark(name, dir) => {
+                                write!(
+                                    f,
+                                    "There is no intersecting street to the {dir} after {name}."
+                                )
+                            }
+                            // The end of the street can always be found, so
+                            // this is never reported. The arm only keeps the
+                            // match exhaustive.
+                            CtxCommandDistance::EndOfStreet => {
+                                write!(f, "You cannot reach the end of the street.")
+                            }
                         }
                     }
                     // A roation command should never be the cause of a command
                     // transformation error.
                     CtxCommand::Rotate(_) => write!(f, "You cannot rotate."),
+                    CtxCommand::Face(dir) => write!(f, "You cannot face {dir}."),
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandError, State};
+    use crate::direction::CardinalDirection;
+    use crate::lang::{Accents, Lexeme, WordError};
+    use crate::map::{fixture, BuildingId, RoadGraph};
+    use crate::pose::Pose;
+    use std::str::FromStr;
+
+    /// Returns a state on a single street whose word bank only contains the
+    /// given words.
+    fn state_with_bank(words: &[Lexeme]) -> State {
+        let map = fixture::map(&["ns,1,0,5,0,Calle 1"], &["0,4,1,1,la casa"]);
+        let graph = RoadGraph::new(&map);
+        State::new(
+            map,
+            graph,
+            Some(words.to_vec()),
+            None,
+            Accents::Strict,
+            Pose::new(1, 0, CardinalDirection::North),
+            BuildingId::new(0),
+        )
+    }
+
+    #[test]
+    fn reject_words_not_in_bank() {
+        let mut state = state_with_bank(&[
+            Lexeme::Continúa,
+            Lexeme::Hasta,
+            Lexeme::El,
+            Lexeme::Final,
+            Lexeme::De,
+            Lexeme::La,
+            Lexeme::Calle,
+        ]);
+
+        state.set_sentence("continúa hasta el final de la calle".to_string());
+        assert!(state.directions().is_ok());
+
+        // "gira" is a known word, but it is not in the bank.
+        state.set_sentence("gira a la derecha".to_string());
+        match state.directions() {
+            Err(CommandError::WordsNotInBank(words)) => {
+                assert_eq!(words, [Lexeme::Gira, Lexeme::A, Lexeme::Derecha])
+            }
+            other => panic!("expected WordsNotInBank, got {other:?}"),
+        }
+
+        // "xyzzy" is not a word at all.
+        state.set_sentence("continúa hasta xyzzy".to_string());
+        match state.directions() {
+            Err(CommandError::UnrecognizedWords { source }) => {
+                assert!(matches!(&source.0[..], [WordError::Unknown(w)] if w.word == "xyzzy"))
+            }
+            other => panic!("expected UnrecognizedWords, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reject_digit_distance_not_in_bank() {
+        let words = [
+            Lexeme::Continúa,
+            Lexeme::Derecho,
+            Lexeme::Quadras,
+            Lexeme::Hasta,
+            Lexeme::La,
+            Lexeme::Calle,
+        ];
+        let mut state = state_with_bank(&words);
+
+        // A numeral that names a street needs no entry in the bank.
+        state.set_sentence("continúa hasta la Calle 1".to_string());
+        assert!(state.directions().is_ok());
+
+        // A numeral that counts blocks does.
+        let seven = Lexeme::from_str("7").unwrap();
+        state.set_sentence("continúa derecho 7 quadras".to_string());
+        match state.directions() {
+            Err(CommandError::WordsNotInBank(words)) => assert_eq!(words, [seven]),
+            other => panic!("expected WordsNotInBank, got {other:?}"),
+        }
+
+        let mut state = state_with_bank(&[&words[..], &[seven]].concat());
+        state.set_sentence("continúa derecho 7 quadras".to_string());
+        assert!(state.directions().is_ok());
+    }
+}
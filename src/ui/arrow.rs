@@ -43,7 +43,7 @@ impl Arrow {
         self.0
             .iter()
             .find(|tile| tile.position == position)
-            .map(|tile| tile.clone())
+            .copied()
     }
 
     fn append_tiles(tiles: &mut Vec<ArrowTile>, other: &mut Vec<ArrowTile>) {
@@ -119,6 +119,18 @@ impl Arrow {
                     (CardinalDirection::West, TurnDirection::Right) => {
                         ArrowTurnDirection::WestToSouth
                     }
+                    (CardinalDirection::North, TurnDirection::Around) => {
+                        ArrowTurnDirection::NorthToSouth
+                    }
+                    (CardinalDirection::East, TurnDirection::Around) => {
+                        ArrowTurnDirection::EastToWest
+                    }
+                    (CardinalDirection::South, TurnDirection::Around) => {
+                        ArrowTurnDirection::SouthToNorth
+                    }
+                    (CardinalDirection::West, TurnDirection::Around) => {
+                        ArrowTurnDirection::WestToEast
+                    }
                 };
                 let seg = if is_final {
                     ArrowSegment::TurnFinal(arrow_dir)
@@ -168,6 +180,10 @@ pub enum ArrowTurnDirection {
     SouthToWest,
     WestToNorth,
     WestToSouth,
+    NorthToSouth,
+    EastToWest,
+    SouthToNorth,
+    WestToEast,
 }
 
 impl ArrowTurnDirection {
@@ -181,6 +197,41 @@ impl ArrowTurnDirection {
             ArrowTurnDirection::SouthToWest => CardinalDirection::West,
             ArrowTurnDirection::WestToNorth => CardinalDirection::North,
             ArrowTurnDirection::WestToSouth => CardinalDirection::South,
+            ArrowTurnDirection::NorthToSouth => CardinalDirection::South,
+            ArrowTurnDirection::EastToWest => CardinalDirection::West,
+            ArrowTurnDirection::SouthToNorth => CardinalDirection::North,
+            ArrowTurnDirection::WestToEast => CardinalDirection::East,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vek::Vec2;
+
+    use super::{Arrow, ArrowSegment, ArrowTurnDirection};
+    use crate::cmd::AbsoluteCommand;
+    use crate::direction::{CardinalDirection, TurnDirection};
+    use crate::pose::Pose;
+
+    #[test]
+    fn arrow_u_turn() {
+        let pose = Pose::new(3, 4, CardinalDirection::East);
+        let arrow = Arrow::new(
+            &pose,
+            &[
+                AbsoluteCommand::Forward(2),
+                AbsoluteCommand::Rotate(TurnDirection::Around),
+            ],
+        );
+        let segment = arrow.get(Vec2::new(5, 4)).map(|tile| tile.segment());
+        assert_eq!(
+            segment,
+            Some(ArrowSegment::TurnFinal(ArrowTurnDirection::EastToWest))
+        );
+        assert_eq!(
+            ArrowTurnDirection::EastToWest.final_direction(),
+            CardinalDirection::West
+        );
+    }
+}
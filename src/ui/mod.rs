@@ -1,6 +1,6 @@
 mod arrow;
 
-pub use arrow::{ArrowSegment, ArrowTile, ArrowTurnDirection};
+pub use arrow::{ArrowSegment, ArrowTile};
 use iter_tools::Itertools;
 
 use crate::{
@@ -37,7 +37,9 @@ impl UiGrid {
     }
 }
 
+#[derive(Default)]
 pub enum UiTile {
+    #[default]
     Empty,
     Road {
         player: Option<CardinalDirection>,
@@ -62,12 +64,6 @@ impl UiTile {
     }
 }
 
-impl Default for UiTile {
-    fn default() -> Self {
-        Self::Empty
-    }
-}
-
 impl Display for UiGrid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut row_strings = self